@@ -0,0 +1,31 @@
+use anchor_lang::{AccountDeserialize, Result};
+use on_chain_vault::state::{AllowlistEntry, DepositorPosition, PendingWithdrawal, Proposal, Stream, Vault};
+
+/// Decodes raw account data, checking the account discriminator first.
+pub fn decode_account<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_vault(data: &[u8]) -> Result<Vault> {
+    decode_account(data)
+}
+
+pub fn decode_position(data: &[u8]) -> Result<DepositorPosition> {
+    decode_account(data)
+}
+
+pub fn decode_allowlist_entry(data: &[u8]) -> Result<AllowlistEntry> {
+    decode_account(data)
+}
+
+pub fn decode_pending_withdrawal(data: &[u8]) -> Result<PendingWithdrawal> {
+    decode_account(data)
+}
+
+pub fn decode_proposal(data: &[u8]) -> Result<Proposal> {
+    decode_account(data)
+}
+
+pub fn decode_stream(data: &[u8]) -> Result<Stream> {
+    decode_account(data)
+}
@@ -0,0 +1,43 @@
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use on_chain_vault::events::{DepositEvent, InitializeVaultEvent, ToggleLockEvent, WithdrawEvent};
+
+/// Prefix of the log line `emit!` writes each event to.
+const PROGRAM_DATA: &str = "Program data: ";
+
+pub enum VaultEvent {
+    InitializeVault(InitializeVaultEvent),
+    Deposit(DepositEvent),
+    Withdraw(WithdrawEvent),
+    ToggleLock(ToggleLockEvent),
+}
+
+impl VaultEvent {
+    /// Decodes an event from its serialized form (discriminator followed by the
+    /// borsh encoded event). Returns `None` for events this client does not decode.
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        fn parse<T: Discriminator + AnchorDeserialize>(data: &[u8]) -> Option<T> {
+            let mut payload = data.strip_prefix(T::DISCRIMINATOR)?;
+            T::deserialize(&mut payload).ok()
+        }
+
+        parse(data)
+            .map(Self::InitializeVault)
+            .or_else(|| parse(data).map(Self::Deposit))
+            .or_else(|| parse(data).map(Self::Withdraw))
+            .or_else(|| parse(data).map(Self::ToggleLock))
+    }
+
+    /// Decodes an event from a single `Program data: <base64>` log line.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let data = STANDARD.decode(log.strip_prefix(PROGRAM_DATA)?).ok()?;
+
+        Self::decode(&data)
+    }
+}
+
+/// Decodes every vault event found in a transaction's log messages, in order.
+pub fn decode_events<S: AsRef<str>>(logs: &[S]) -> Vec<VaultEvent> {
+    logs.iter().filter_map(|log| VaultEvent::from_log(log.as_ref())).collect()
+}
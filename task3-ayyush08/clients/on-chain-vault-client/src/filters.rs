@@ -0,0 +1,41 @@
+//! `getProgramAccounts` filters for enumerating vault accounts, e.g.
+//!
+//! ```ignore
+//! let config = RpcProgramAccountsConfig {
+//!     filters: Some(filters::vaults_by_authority(&authority)),
+//!     ..Default::default()
+//! };
+//! let vaults = rpc.get_program_accounts_with_config(&PROGRAM_ID, config)?;
+//! ```
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use on_chain_vault::state::Vault;
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+
+/// Offset of `Vault::vault_authority`, right after the account discriminator.
+pub const VAULT_AUTHORITY_OFFSET: usize = 8;
+/// Offset of `Vault::creator`.
+pub const VAULT_CREATOR_OFFSET: usize = VAULT_AUTHORITY_OFFSET + 32;
+
+/// Matches every vault account of the program.
+pub fn vault_accounts() -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, Vault::DISCRIMINATOR.to_vec()))
+}
+
+/// Matches the vaults currently controlled by `authority`.
+pub fn vaults_by_authority(authority: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        vault_accounts(),
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(VAULT_AUTHORITY_OFFSET, authority.to_bytes().to_vec())),
+    ]
+}
+
+/// Matches the vaults opened by `creator`, whose addresses derive from it
+/// whoever their authority is now.
+pub fn vaults_by_creator(creator: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        vault_accounts(),
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(VAULT_CREATOR_OFFSET, creator.to_bytes().to_vec())),
+    ]
+}
@@ -0,0 +1,445 @@
+//! Instruction builders, one per `on_chain_vault` instruction.
+//!
+//! Builders take the vault address rather than deriving it, since the vault
+//! stays seeded by its creator after the authority has been handed over.
+//! `init_vault` is the exception and derives the vault from the new authority
+//! and the vault id.
+
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
+use on_chain_vault::state::ProposalAction;
+use on_chain_vault::{accounts, instruction};
+
+use crate::pda::{
+    allowlist_address, pending_withdrawal_address, position_address, proposal_address, stream_address, vault_address,
+};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: on_chain_vault::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn init_vault(vault_authority: &Pubkey, id: u64, locked: bool, withdraw_delay: i64) -> Instruction {
+    build(
+        accounts::InitializeVault {
+            vault_authority: *vault_authority,
+            vault: vault_address(vault_authority, id).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitVault { id, locked, withdraw_delay },
+    )
+}
+
+pub fn deposit(user: &Pubkey, vault: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::Deposit {
+            user: *user,
+            vault: *vault,
+            position: position_address(vault, user).0,
+            allowlist_entry: allowlist_address(vault, user).0,
+            system_program: system_program::ID,
+        },
+        instruction::Deposit { amount },
+    )
+}
+
+fn withdraw_accounts(vault_authority: &Pubkey, vault: &Pubkey) -> accounts::Withdraw {
+    accounts::Withdraw {
+        vault_authority: *vault_authority,
+        vault: *vault,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn withdraw(vault_authority: &Pubkey, vault: &Pubkey, amount: u64) -> Instruction {
+    build(withdraw_accounts(vault_authority, vault), instruction::Withdraw { amount })
+}
+
+pub fn withdraw_all(vault_authority: &Pubkey, vault: &Pubkey) -> Instruction {
+    build(withdraw_accounts(vault_authority, vault), instruction::WithdrawAll {})
+}
+
+/// Pays each `(recipient, amount)` pair, recipients are passed as writable
+/// remaining accounts in the same order as the amounts.
+pub fn withdraw_to_many(vault_authority: &Pubkey, vault: &Pubkey, payouts: &[(Pubkey, u64)]) -> Instruction {
+    let amounts = payouts.iter().map(|(_, amount)| *amount).collect();
+    let mut ix = build(withdraw_accounts(vault_authority, vault), instruction::WithdrawToMany { amounts });
+
+    ix.accounts
+        .extend(payouts.iter().map(|(recipient, _)| AccountMeta::new(*recipient, false)));
+
+    ix
+}
+
+pub fn toggle_lock(vault_authority: &Pubkey, vault: &Pubkey) -> Instruction {
+    build(
+        accounts::ToggleLock {
+            vault_authority: *vault_authority,
+            vault: *vault,
+            system_program: system_program::ID,
+        },
+        instruction::ToggleLock {},
+    )
+}
+
+pub fn set_pause(vault_authority: &Pubkey, vault: &Pubkey, deposits_paused: bool, withdrawals_paused: bool) -> Instruction {
+    build(
+        accounts::SetPause {
+            vault_authority: *vault_authority,
+            vault: *vault,
+        },
+        instruction::SetPause { deposits_paused, withdrawals_paused },
+    )
+}
+
+/// Deposits `amount` tokens of `mint` from `user_token_account`, `token_program`
+/// is either the Token or the Token-2022 program owning the mint.
+pub fn deposit_token(
+    user: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    user_token_account: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::DepositToken {
+            user: *user,
+            vault: *vault,
+            mint: *mint,
+            user_token_account: *user_token_account,
+            vault_token_account: get_associated_token_address_with_program_id(vault, mint, token_program),
+            allowlist_entry: allowlist_address(vault, user).0,
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::DepositToken { amount },
+    )
+}
+
+pub fn withdraw_token(
+    vault_authority: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::WithdrawToken {
+            vault_authority: *vault_authority,
+            vault: *vault,
+            mint: *mint,
+            vault_token_account: get_associated_token_address_with_program_id(vault, mint, token_program),
+            authority_token_account: get_associated_token_address_with_program_id(vault_authority, mint, token_program),
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::WithdrawToken { amount },
+    )
+}
+
+pub fn depositor_withdraw(user: &Pubkey, vault: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::DepositorWithdraw {
+            user: *user,
+            vault: *vault,
+            position: position_address(vault, user).0,
+        },
+        instruction::DepositorWithdraw { amount },
+    )
+}
+
+pub fn request_withdraw(vault_authority: &Pubkey, vault: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::RequestWithdraw {
+            vault_authority: *vault_authority,
+            vault: *vault,
+            pending_withdrawal: pending_withdrawal_address(vault).0,
+            system_program: system_program::ID,
+        },
+        instruction::RequestWithdraw { amount },
+    )
+}
+
+pub fn execute_withdraw(vault_authority: &Pubkey, vault: &Pubkey) -> Instruction {
+    build(
+        accounts::ExecuteWithdraw {
+            vault_authority: *vault_authority,
+            vault: *vault,
+            pending_withdrawal: pending_withdrawal_address(vault).0,
+        },
+        instruction::ExecuteWithdraw {},
+    )
+}
+
+pub fn cancel_withdraw(vault_authority: &Pubkey, vault: &Pubkey) -> Instruction {
+    build(
+        accounts::CancelWithdraw {
+            vault_authority: *vault_authority,
+            vault: *vault,
+            pending_withdrawal: pending_withdrawal_address(vault).0,
+        },
+        instruction::CancelWithdraw {},
+    )
+}
+
+pub fn set_multisig(vault_authority: &Pubkey, vault: &Pubkey, signers: Vec<Pubkey>, threshold: u8) -> Instruction {
+    build(
+        accounts::SetMultisig {
+            vault_authority: *vault_authority,
+            vault: *vault,
+        },
+        instruction::SetMultisig { signers, threshold },
+    )
+}
+
+/// `proposal_id` must be the vault's current `proposal_count`.
+pub fn create_proposal(proposer: &Pubkey, vault: &Pubkey, proposal_id: u64, action: ProposalAction) -> Instruction {
+    build(
+        accounts::CreateProposal {
+            proposer: *proposer,
+            vault: *vault,
+            proposal: proposal_address(vault, proposal_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateProposal { action },
+    )
+}
+
+pub fn approve_proposal(signer: &Pubkey, vault: &Pubkey, proposal_id: u64) -> Instruction {
+    build(
+        accounts::ApproveProposal {
+            signer: *signer,
+            vault: *vault,
+            proposal: proposal_address(vault, proposal_id).0,
+        },
+        instruction::ApproveProposal {},
+    )
+}
+
+/// `recipient` is only required for withdraw proposals, see `execute_token_proposal`
+/// for token withdraw proposals.
+pub fn execute_proposal(
+    executor: &Pubkey,
+    vault: &Pubkey,
+    proposal_id: u64,
+    proposer: &Pubkey,
+    recipient: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::ExecuteProposal {
+            executor: *executor,
+            vault: *vault,
+            proposal: proposal_address(vault, proposal_id).0,
+            proposer: *proposer,
+            recipient,
+            mint: None,
+            vault_token_account: None,
+            token_program: None,
+        },
+        instruction::ExecuteProposal {},
+    )
+}
+
+/// Executes a token withdraw proposal paying `recipient_token_account` in tokens of `mint`.
+pub fn execute_token_proposal(
+    executor: &Pubkey,
+    vault: &Pubkey,
+    proposal_id: u64,
+    proposer: &Pubkey,
+    mint: &Pubkey,
+    recipient_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::ExecuteProposal {
+            executor: *executor,
+            vault: *vault,
+            proposal: proposal_address(vault, proposal_id).0,
+            proposer: *proposer,
+            recipient: Some(*recipient_token_account),
+            mint: Some(*mint),
+            vault_token_account: Some(get_associated_token_address_with_program_id(vault, mint, token_program)),
+            token_program: Some(*token_program),
+        },
+        instruction::ExecuteProposal {},
+    )
+}
+
+pub fn close_vault(vault_authority: &Pubkey, vault: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseVault {
+            vault_authority: *vault_authority,
+            vault: *vault,
+        },
+        instruction::CloseVault {},
+    )
+}
+
+pub fn propose_authority(vault_authority: &Pubkey, vault: &Pubkey, new_authority: Pubkey) -> Instruction {
+    build(
+        accounts::ProposeAuthority {
+            vault_authority: *vault_authority,
+            vault: *vault,
+        },
+        instruction::ProposeAuthority { new_authority },
+    )
+}
+
+pub fn accept_authority(new_authority: &Pubkey, vault: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptAuthority {
+            new_authority: *new_authority,
+            vault: *vault,
+        },
+        instruction::AcceptAuthority {},
+    )
+}
+
+pub fn set_withdraw_limit(vault_authority: &Pubkey, vault: &Pubkey, withdraw_limit: u64, limit_window: i64) -> Instruction {
+    build(
+        accounts::SetWithdrawLimit {
+            vault_authority: *vault_authority,
+            vault: *vault,
+        },
+        instruction::SetWithdrawLimit { withdraw_limit, limit_window },
+    )
+}
+
+pub fn set_allowlist_enabled(vault_authority: &Pubkey, vault: &Pubkey, allowlist_enabled: bool) -> Instruction {
+    build(
+        accounts::SetAllowlistEnabled {
+            vault_authority: *vault_authority,
+            vault: *vault,
+        },
+        instruction::SetAllowlistEnabled { allowlist_enabled },
+    )
+}
+
+pub fn add_to_allowlist(vault_authority: &Pubkey, vault: &Pubkey, depositor: Pubkey) -> Instruction {
+    build(
+        accounts::AddToAllowlist {
+            vault_authority: *vault_authority,
+            vault: *vault,
+            allowlist_entry: allowlist_address(vault, &depositor).0,
+            system_program: system_program::ID,
+        },
+        instruction::AddToAllowlist { depositor },
+    )
+}
+
+pub fn remove_from_allowlist(vault_authority: &Pubkey, vault: &Pubkey, depositor: &Pubkey) -> Instruction {
+    build(
+        accounts::RemoveFromAllowlist {
+            vault_authority: *vault_authority,
+            vault: *vault,
+            allowlist_entry: allowlist_address(vault, depositor).0,
+        },
+        instruction::RemoveFromAllowlist {},
+    )
+}
+
+pub fn set_guardian(vault_authority: &Pubkey, vault: &Pubkey, guardian: Option<Pubkey>) -> Instruction {
+    build(
+        accounts::SetGuardian {
+            vault_authority: *vault_authority,
+            vault: *vault,
+        },
+        instruction::SetGuardian { guardian },
+    )
+}
+
+pub fn guardian_lock(guardian: &Pubkey, vault: &Pubkey) -> Instruction {
+    build(
+        accounts::GuardianLock {
+            guardian: *guardian,
+            vault: *vault,
+        },
+        instruction::GuardianLock {},
+    )
+}
+
+pub fn set_reward_rate(vault_authority: &Pubkey, vault: &Pubkey, reward_rate_bps: u16) -> Instruction {
+    build(
+        accounts::SetRewardRate {
+            vault_authority: *vault_authority,
+            vault: *vault,
+        },
+        instruction::SetRewardRate { reward_rate_bps },
+    )
+}
+
+pub fn fund_rewards(funder: &Pubkey, vault: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::FundRewards {
+            funder: *funder,
+            vault: *vault,
+            system_program: system_program::ID,
+        },
+        instruction::FundRewards { amount },
+    )
+}
+
+pub fn claim_rewards(user: &Pubkey, vault: &Pubkey) -> Instruction {
+    build(
+        accounts::ClaimRewards {
+            user: *user,
+            vault: *vault,
+            position: position_address(vault, user).0,
+        },
+        instruction::ClaimRewards {},
+    )
+}
+
+pub fn migrate_vault(payer: &Pubkey, vault: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateVault {
+            payer: *payer,
+            vault: *vault,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateVault {},
+    )
+}
+
+pub fn create_stream(vault_authority: &Pubkey, vault: &Pubkey, recipient: &Pubkey, rate: u64, cap: u64) -> Instruction {
+    build(
+        accounts::CreateStream {
+            vault_authority: *vault_authority,
+            vault: *vault,
+            stream: stream_address(vault, recipient).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateStream { recipient: *recipient, rate, cap },
+    )
+}
+
+pub fn claim_stream(recipient: &Pubkey, vault: &Pubkey) -> Instruction {
+    build(
+        accounts::ClaimStream {
+            recipient: *recipient,
+            vault: *vault,
+            stream: stream_address(vault, recipient).0,
+        },
+        instruction::ClaimStream {},
+    )
+}
+
+pub fn cancel_stream(vault_authority: &Pubkey, vault: &Pubkey, recipient: &Pubkey) -> Instruction {
+    build(
+        accounts::CancelStream {
+            vault_authority: *vault_authority,
+            vault: *vault,
+            stream: stream_address(vault, recipient).0,
+            recipient: *recipient,
+        },
+        instruction::CancelStream {},
+    )
+}
@@ -0,0 +1,19 @@
+//! Off-chain client for the on-chain vault program.
+//!
+//! - [`pda`]: program derived addresses used by the vault
+//! - [`instructions`]: one builder per program instruction
+//! - [`accounts`]: decoders for raw account data
+//! - [`events`]: decoders for events emitted in transaction logs
+//! - [`filters`]: `getProgramAccounts` filters to enumerate vaults
+
+pub mod accounts;
+pub mod events;
+pub mod filters;
+pub mod instructions;
+pub mod pda;
+
+pub use on_chain_vault::ID as PROGRAM_ID;
+pub use on_chain_vault::errors::VaultError;
+pub use on_chain_vault::state::{
+    AllowlistEntry, DepositorPosition, PendingWithdrawal, Proposal, ProposalAction, Stream, Vault,
+};
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::Pubkey;
+
+/// Vault `id` of `creator`, seeded with `[b"vault", creator, id]`. The seed stays
+/// the creator's key after the vault authority has been handed over.
+pub fn vault_address(creator: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault", creator.as_ref(), &id.to_le_bytes()], &on_chain_vault::ID)
+}
+
+/// Vault of `vault_authority` created by the original program, seeded with
+/// `[b"vault", vault_authority]`. It keeps this address after `migrate_vault`.
+pub fn original_vault_address(vault_authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault", vault_authority.as_ref()], &on_chain_vault::ID)
+}
+
+pub fn position_address(vault: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"position", vault.as_ref(), user.as_ref()], &on_chain_vault::ID)
+}
+
+pub fn allowlist_address(vault: &Pubkey, depositor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"allowlist", vault.as_ref(), depositor.as_ref()], &on_chain_vault::ID)
+}
+
+pub fn pending_withdrawal_address(vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"pending_withdrawal", vault.as_ref()], &on_chain_vault::ID)
+}
+
+pub fn proposal_address(vault: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"proposal", vault.as_ref(), &id.to_le_bytes()], &on_chain_vault::ID)
+}
+
+pub fn stream_address(vault: &Pubkey, recipient: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"stream", vault.as_ref(), recipient.as_ref()], &on_chain_vault::ID)
+}
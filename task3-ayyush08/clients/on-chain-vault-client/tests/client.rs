@@ -0,0 +1,162 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountSerialize, Event, InstructionData};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use on_chain_vault::events::{DepositEvent, ToggleLockEvent, WithdrawToEvent};
+use on_chain_vault::instruction;
+use on_chain_vault_client::accounts::{decode_position, decode_vault};
+use on_chain_vault_client::events::{decode_events, VaultEvent};
+use on_chain_vault_client::{filters, instructions, pda, DepositorPosition};
+use solana_rpc_client_api::filter::RpcFilterType;
+
+fn matches_all(filters: &[RpcFilterType], data: &[u8]) -> bool {
+    filters.iter().all(|filter| match filter {
+        RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(data),
+        _ => false,
+    })
+}
+
+fn program_data_log(event: &impl Event) -> String {
+    format!("Program data: {}", STANDARD.encode(event.data()))
+}
+
+#[test]
+fn vault_address_matches_program_seeds() {
+    let creator = Pubkey::new_unique();
+    let (vault, bump) = pda::vault_address(&creator, 7);
+
+    let expected = Pubkey::create_program_address(
+        &[b"vault", creator.as_ref(), &7u64.to_le_bytes(), &[bump]],
+        &on_chain_vault::ID,
+    )
+    .unwrap();
+    assert_eq!(vault, expected);
+    assert_ne!(vault, pda::vault_address(&creator, 0).0);
+}
+
+#[test]
+fn deposit_builder_accounts() {
+    let user = Pubkey::new_unique();
+    let (vault, _) = pda::vault_address(&Pubkey::new_unique(), 0);
+
+    let ix = instructions::deposit(&user, &vault, 42);
+
+    assert_eq!(ix.program_id, on_chain_vault::ID);
+    assert_eq!(ix.data, instruction::Deposit { amount: 42 }.data());
+    assert_eq!(ix.accounts[0].pubkey, user);
+    assert!(ix.accounts[0].is_signer && ix.accounts[0].is_writable);
+    assert_eq!(ix.accounts[1].pubkey, vault);
+    assert_eq!(ix.accounts[2].pubkey, pda::position_address(&vault, &user).0);
+    assert_eq!(ix.accounts[3].pubkey, pda::allowlist_address(&vault, &user).0);
+}
+
+#[test]
+fn withdraw_to_many_appends_writable_recipients() {
+    let authority = Pubkey::new_unique();
+    let (vault, _) = pda::vault_address(&authority, 0);
+    let payouts = [(Pubkey::new_unique(), 1), (Pubkey::new_unique(), 2)];
+
+    let ix = instructions::withdraw_to_many(&authority, &vault, &payouts);
+
+    assert_eq!(ix.data, instruction::WithdrawToMany { amounts: vec![1, 2] }.data());
+    let recipients = &ix.accounts[ix.accounts.len() - 2..];
+    for (meta, (recipient, _)) in recipients.iter().zip(payouts) {
+        assert_eq!(meta.pubkey, recipient);
+        assert!(meta.is_writable && !meta.is_signer);
+    }
+}
+
+#[test]
+fn decodes_events_from_logs() {
+    let vault = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+
+    let logs = vec![
+        "Program ARmiAGe6oAEq5BKguHydD3zt2n5PkV2Q5PLA1McuMkJT invoke [1]".to_string(),
+        program_data_log(&DepositEvent { amount: 7, user, vault, position_balance: 7 }),
+        program_data_log(&WithdrawToEvent {
+            amount: 1,
+            vault_authority: user,
+            vault,
+            recipient: user,
+        }),
+        program_data_log(&ToggleLockEvent {
+            vault,
+            vault_authority: user,
+            locked: true,
+            deposits_paused: true,
+            withdrawals_paused: true,
+        }),
+        "Program data: not base64".to_string(),
+    ];
+
+    let events = decode_events(&logs);
+
+    assert_eq!(events.len(), 2);
+    assert!(matches!(&events[0], VaultEvent::Deposit(event) if event.amount == 7 && event.user == user));
+    assert!(matches!(&events[1], VaultEvent::ToggleLock(event) if event.locked && event.vault == vault));
+}
+
+#[test]
+fn decode_checks_account_discriminator() {
+    let position = DepositorPosition {
+        vault: Pubkey::new_unique(),
+        user: Pubkey::new_unique(),
+        balance: 5,
+        bump: 255,
+        pending_rewards: 0,
+        reward_index: 0,
+    };
+
+    let mut data = Vec::new();
+    position.try_serialize(&mut data).unwrap();
+
+    assert_eq!(decode_position(&data).unwrap().balance, 5);
+    assert!(decode_vault(&data).is_err());
+}
+
+#[test]
+fn vault_filters_match_account_layout() {
+    let authority = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+
+    let mut data = Vec::new();
+    on_chain_vault_client::Vault {
+        vault_authority: authority,
+        creator,
+        id: 3,
+        bump: 255,
+        pending_authority: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        withdraw_delay: 0,
+        signers: Vec::new(),
+        threshold: 0,
+        proposal_count: 0,
+        depositor_balance: 0,
+        withdraw_limit: 0,
+        limit_window: 0,
+        window_start: 0,
+        window_withdrawn: 0,
+        allowlist_enabled: false,
+        guardian: None,
+        reward_rate_bps: 0,
+        reward_reserve: 0,
+        total_deposited: 0,
+        total_withdrawn: 0,
+        deposit_count: 0,
+        last_activity_slot: 0,
+        version: on_chain_vault::state::VAULT_VERSION,
+        reward_index: 0,
+        reward_index_updated: 0,
+        open_streams: 0,
+        legacy_address: false,
+        reserved: [0; on_chain_vault::state::VAULT_RESERVED_SPACE],
+    }
+    .try_serialize(&mut data)
+    .unwrap();
+
+    assert!(matches_all(&filters::vaults_by_authority(&authority), &data));
+    assert!(matches_all(&filters::vaults_by_creator(&creator), &data));
+    assert!(!matches_all(&filters::vaults_by_authority(&creator), &data));
+}
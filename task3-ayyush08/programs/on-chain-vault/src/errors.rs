@@ -8,4 +8,56 @@ pub enum VaultError {
     Overflow,
     #[msg("Insufficient balance")]
     InsufficientBalance,
+    #[msg("Withdrawal would leave the vault below its rent-exempt minimum")]
+    BelowRentExemptMinimum,
+    #[msg("Withdraw delay must not be negative")]
+    InvalidWithdrawDelay,
+    #[msg("Vault withdrawals are timelocked, use request_withdraw")]
+    WithdrawTimelocked,
+    #[msg("Withdrawal is still timelocked")]
+    WithdrawNotReady,
+    #[msg("Vault is in multisig mode, use a proposal")]
+    MultisigRequired,
+    #[msg("Vault is not in multisig mode")]
+    NotMultisig,
+    #[msg("Signer is not a member of the vault multisig")]
+    NotASigner,
+    #[msg("Signer has already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Proposal does not have enough approvals")]
+    InsufficientApprovals,
+    #[msg("Invalid multisig threshold")]
+    InvalidThreshold,
+    #[msg("Too many multisig signers")]
+    TooManySigners,
+    #[msg("Duplicate multisig signer")]
+    DuplicateSigner,
+    #[msg("Recipient does not match the proposal")]
+    InvalidRecipient,
+    #[msg("Vault still holds depositor balances")]
+    OutstandingDeposits,
+    #[msg("Signer is not the pending vault authority")]
+    NotPendingAuthority,
+    #[msg("Withdrawal exceeds the vault's rate limit for the current window")]
+    WithdrawLimitExceeded,
+    #[msg("Rate limit window must be positive")]
+    InvalidLimitWindow,
+    #[msg("Depositor is not on the vault allowlist")]
+    NotAllowlisted,
+    #[msg("Signer is not the vault guardian")]
+    NotGuardian,
+    #[msg("Number of amounts and recipients must match")]
+    RecipientMismatch,
+    #[msg("Signer is not the vault authority")]
+    Unauthorized,
+    #[msg("Vault already uses the current account layout")]
+    AlreadyMigrated,
+    #[msg("Stream rate and cap must be positive")]
+    InvalidStream,
+    #[msg("Withdrawal would take lamports owed to depositors or set aside for rewards")]
+    ReservedFunds,
+    #[msg("Vault still has open streams")]
+    OpenStreams,
+    #[msg("Token accounts do not match the proposal")]
+    InvalidTokenAccounts,
 }
\ No newline at end of file
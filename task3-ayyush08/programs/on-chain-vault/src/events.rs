@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
+use crate::state::ProposalAction;
 
 #[event]
 pub struct InitializeVaultEvent {
     pub vault: Pubkey,
     pub vault_authority: Pubkey,
+    pub id: u64,
     pub locked: bool,
+    pub withdraw_delay: i64,
 }
 
 #[event]
@@ -12,6 +15,7 @@ pub struct DepositEvent {
     pub amount: u64,
     pub user: Pubkey,
     pub vault: Pubkey,
+    pub position_balance: u64,
 }
 
 #[event]
@@ -21,9 +25,225 @@ pub struct WithdrawEvent {
     pub vault: Pubkey,
 }
 
+#[event]
+pub struct RequestWithdrawEvent {
+    pub amount: u64,
+    pub vault_authority: Pubkey,
+    pub vault: Pubkey,
+    pub unlock_timestamp: i64,
+}
+
+#[event]
+pub struct CancelWithdrawEvent {
+    pub amount: u64,
+    pub vault_authority: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct WithdrawToEvent {
+    pub amount: u64,
+    pub vault_authority: Pubkey,
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct WithdrawTokenToEvent {
+    pub amount: u64,
+    pub vault_authority: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct DepositorWithdrawEvent {
+    pub amount: u64,
+    pub user: Pubkey,
+    pub vault: Pubkey,
+    pub position_balance: u64,
+}
+
 #[event]
 pub struct ToggleLockEvent {
     pub vault: Pubkey,
     pub vault_authority: Pubkey,
     pub locked: bool,
-}
\ No newline at end of file
+    pub deposits_paused: bool,
+    pub withdrawals_paused: bool,
+}
+
+#[event]
+pub struct SetPauseEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub deposits_paused: bool,
+    pub withdrawals_paused: bool,
+}
+
+#[event]
+pub struct ProposeAuthorityEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AcceptAuthorityEvent {
+    pub vault: Pubkey,
+    pub previous_authority: Pubkey,
+    pub vault_authority: Pubkey,
+}
+
+#[event]
+pub struct SetWithdrawLimitEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub withdraw_limit: u64,
+    pub limit_window: i64,
+}
+
+#[event]
+pub struct SetAllowlistEnabledEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub allowlist_enabled: bool,
+}
+
+#[event]
+pub struct AddToAllowlistEvent {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+}
+
+#[event]
+pub struct RemoveFromAllowlistEvent {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+}
+
+#[event]
+pub struct SetGuardianEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub guardian: Option<Pubkey>,
+}
+
+#[event]
+pub struct GuardianLockEvent {
+    pub vault: Pubkey,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct SetRewardRateEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub reward_rate_bps: u16,
+}
+
+#[event]
+pub struct FundRewardsEvent {
+    pub amount: u64,
+    pub funder: Pubkey,
+    pub vault: Pubkey,
+    pub reward_reserve: u64,
+}
+
+#[event]
+pub struct ClaimRewardsEvent {
+    pub amount: u64,
+    pub user: Pubkey,
+    pub vault: Pubkey,
+    pub pending_rewards: u64,
+}
+
+#[event]
+pub struct CloseVaultEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct MigrateVaultEvent {
+    pub vault: Pubkey,
+    pub payer: Pubkey,
+    pub previous_version: u8,
+    pub version: u8,
+}
+
+#[event]
+pub struct DepositTokenEvent {
+    pub amount: u64,
+    pub user: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct WithdrawTokenEvent {
+    pub amount: u64,
+    pub vault_authority: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct SetMultisigEvent {
+    pub vault: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct CreateProposalEvent {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+}
+
+#[event]
+pub struct ApproveProposalEvent {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ExecuteProposalEvent {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+    pub action: ProposalAction,
+}
+
+#[event]
+pub struct CreateStreamEvent {
+    pub vault: Pubkey,
+    pub stream: Pubkey,
+    pub recipient: Pubkey,
+    pub rate: u64,
+    pub cap: u64,
+}
+
+#[event]
+pub struct ClaimStreamEvent {
+    pub vault: Pubkey,
+    pub stream: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub claimed: u64,
+}
+
+#[event]
+pub struct CancelStreamEvent {
+    pub vault: Pubkey,
+    pub stream: Pubkey,
+    pub recipient: Pubkey,
+    pub paid: u64,
+    pub refunded: u64,
+}
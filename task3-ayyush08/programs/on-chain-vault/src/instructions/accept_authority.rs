@@ -0,0 +1,43 @@
+//-------------------------------------------------------------------------------
+///
+/// Accept Authority Instruction
+///
+/// Requirements:
+/// - Only the pending authority can accept the vault
+/// - Make the pending authority the vault authority and clear the pending slot
+/// - Emit an accept authority event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::AcceptAuthorityEvent;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.pending_authority == Some(new_authority.key()) @ VaultError::NotPendingAuthority
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let previous_authority = vault.vault_authority;
+
+    vault.vault_authority = ctx.accounts.new_authority.key();
+    vault.pending_authority = None;
+
+    emit!(AcceptAuthorityEvent {
+        vault: vault.key(),
+        previous_authority,
+        vault_authority: vault.vault_authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+//-------------------------------------------------------------------------------
+///
+/// Add To Allowlist Instruction
+///
+/// Requirements:
+/// - Only the vault authority can allowlist depositors
+/// - Create the depositor's allowlist entry PDA
+/// - Emit an add to allowlist event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{AllowlistEntry, Vault};
+use crate::errors::VaultError;
+use crate::events::AddToAllowlistEvent;
+
+#[derive(Accounts)]
+#[instruction(depositor: Pubkey)]
+pub struct AddToAllowlist<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        init,
+        payer = vault_authority,
+        space = 8 + AllowlistEntry::INIT_SPACE,
+        seeds = [b"allowlist", vault.key().as_ref(), depositor.as_ref()],
+        bump
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _add_to_allowlist(ctx: Context<AddToAllowlist>, depositor: Pubkey) -> Result<()> {
+    let allowlist_entry = &mut ctx.accounts.allowlist_entry;
+
+    allowlist_entry.vault = ctx.accounts.vault.key();
+    allowlist_entry.depositor = depositor;
+    allowlist_entry.bump = ctx.bumps.allowlist_entry;
+
+    emit!(AddToAllowlistEvent {
+        vault: allowlist_entry.vault,
+        depositor,
+    });
+
+    Ok(())
+}
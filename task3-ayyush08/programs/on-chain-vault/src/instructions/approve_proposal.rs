@@ -0,0 +1,59 @@
+//-------------------------------------------------------------------------------
+///
+/// Approve Proposal Instruction
+///
+/// Requirements:
+/// - Only a multisig signer can approve a proposal
+/// - A signer can approve each proposal only once
+/// - Drop approvals of keys that are no longer signers, so a rotated signer set
+///   always has room to approve
+/// - Emit an approve proposal event with the running approval count
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Proposal, Vault};
+use crate::errors::VaultError;
+use crate::events::ApproveProposalEvent;
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_multisig() @ VaultError::NotMultisig,
+        constraint = vault.is_signer(&signer.key()) @ VaultError::NotASigner
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"proposal", vault.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = vault
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn _approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let proposal = &mut ctx.accounts.proposal;
+    let signer = ctx.accounts.signer.key();
+
+    proposal.approvals.retain(|approver| vault.is_signer(approver));
+
+    if proposal.approvals.contains(&signer) {
+        return Err(VaultError::AlreadyApproved.into());
+    }
+
+    proposal.approvals.push(signer);
+
+    emit!(ApproveProposalEvent {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        signer,
+        approvals: proposal.approvals.len() as u8,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//-------------------------------------------------------------------------------
+///
+/// Cancel Stream Instruction
+///
+/// Requirements:
+/// - Only the vault authority can cancel a stream
+/// - Verify that vault withdrawals are not paused
+/// - Pay the recipient what has accrued up to now and add it to the vault statistics
+/// - Refund the rest of the cap to the vault
+/// - Close the stream, returning its rent to the vault authority, and count it as closed on the vault
+/// - Emit a cancel stream event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Stream, Vault};
+use crate::errors::VaultError;
+use crate::events::CancelStreamEvent;
+
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        close = vault_authority,
+        seeds = [b"stream", vault.key().as_ref(), recipient.key().as_ref()],
+        bump = stream.bump,
+        has_one = vault,
+        has_one = recipient
+    )]
+    pub stream: Account<'info, Stream>,
+    /// CHECK: Must be the stream recipient, only receives lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+pub fn _cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let stream = &mut ctx.accounts.stream;
+
+    if vault.withdrawals_paused {
+        return Err(VaultError::VaultLocked.into());
+    }
+
+    let clock = Clock::get()?;
+    let paid = stream.claimable(clock.unix_timestamp)?;
+
+    vault.record_payout(paid, clock.slot)?;
+    stream.record_claim(paid, clock.unix_timestamp)?;
+    let refunded = stream.remaining()?;
+    let released = paid.checked_add(refunded).ok_or(VaultError::Overflow)?;
+
+    vault.open_streams = vault.open_streams.checked_sub(1).ok_or(VaultError::Overflow)?;
+
+    **stream.to_account_info().lamports.borrow_mut() -= released;
+    **ctx.accounts.recipient.to_account_info().lamports.borrow_mut() += paid;
+    **vault.to_account_info().lamports.borrow_mut() += refunded;
+
+    emit!(CancelStreamEvent {
+        vault: vault.key(),
+        stream: stream.key(),
+        recipient: ctx.accounts.recipient.key(),
+        paid,
+        refunded,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//-------------------------------------------------------------------------------
+///
+/// Cancel Withdraw Instruction
+///
+/// Requirements:
+/// - Only the vault authority can cancel a pending withdrawal
+/// - Close the pending withdrawal and refund its rent to the vault authority
+/// - Emit a cancel withdraw event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{PendingWithdrawal, Vault};
+use crate::errors::VaultError;
+use crate::events::CancelWithdrawEvent;
+
+#[derive(Accounts)]
+pub struct CancelWithdraw<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        close = vault_authority,
+        seeds = [b"pending_withdrawal", vault.key().as_ref()],
+        bump = pending_withdrawal.bump,
+        has_one = vault
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+}
+
+pub fn _cancel_withdraw(ctx: Context<CancelWithdraw>) -> Result<()> {
+    emit!(CancelWithdrawEvent {
+        amount: ctx.accounts.pending_withdrawal.amount,
+        vault_authority: ctx.accounts.vault_authority.key(),
+        vault: ctx.accounts.vault.key(),
+    });
+
+    Ok(())
+}
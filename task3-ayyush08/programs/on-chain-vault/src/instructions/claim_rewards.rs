@@ -0,0 +1,74 @@
+//-------------------------------------------------------------------------------
+///
+/// Claim Rewards Instruction
+///
+/// Requirements:
+/// - Verify that vault withdrawals are not paused
+/// - Accrue the depositor's pending rewards
+/// - Pay out as much of the pending rewards as the reward reserve covers,
+///   the rest stays pending until the reserve is topped up
+/// - Emit a claim rewards event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{DepositorPosition, Vault};
+use crate::errors::VaultError;
+use crate::events::ClaimRewardsEvent;
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"position", vault.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        has_one = vault,
+        has_one = user
+    )]
+    pub position: Account<'info, DepositorPosition>,
+}
+
+pub fn _claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let position = &mut ctx.accounts.position;
+
+    if vault.withdrawals_paused {
+        return Err(VaultError::VaultLocked.into());
+    }
+
+    vault.update_reward_index(Clock::get()?.unix_timestamp)?;
+    position.accrue_rewards(vault.reward_index)?;
+
+    let amount = position.pending_rewards.min(vault.reward_reserve);
+
+    if amount == 0 {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+
+    if Vault::surplus_lamports(&vault.to_account_info())? < amount {
+        return Err(VaultError::BelowRentExemptMinimum.into());
+    }
+
+    position.pending_rewards -= amount;
+    vault.reward_reserve -= amount;
+
+    **vault.to_account_info().lamports.borrow_mut() -= amount;
+    **ctx.accounts.user.to_account_info().lamports.borrow_mut() += amount;
+
+    emit!(ClaimRewardsEvent {
+        amount,
+        user: ctx.accounts.user.key(),
+        vault: vault.key(),
+        pending_rewards: position.pending_rewards,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//-------------------------------------------------------------------------------
+///
+/// Claim Stream Instruction
+///
+/// Requirements:
+/// - Only the stream recipient can claim
+/// - Verify that vault withdrawals are not paused
+/// - Pay out `rate * elapsed` lamports since the last claim, up to what is left of the cap
+/// - Add the payout to the vault statistics, the cap already counted against the
+///   vault's rate limit when the stream was created
+/// - Emit a claim stream event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Stream, Vault};
+use crate::errors::VaultError;
+use crate::events::ClaimStreamEvent;
+
+#[derive(Accounts)]
+pub struct ClaimStream<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"stream", vault.key().as_ref(), recipient.key().as_ref()],
+        bump = stream.bump,
+        has_one = vault,
+        has_one = recipient
+    )]
+    pub stream: Account<'info, Stream>,
+}
+
+pub fn _claim_stream(ctx: Context<ClaimStream>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let stream = &mut ctx.accounts.stream;
+
+    if vault.withdrawals_paused {
+        return Err(VaultError::VaultLocked.into());
+    }
+
+    let clock = Clock::get()?;
+    let amount = stream.claimable(clock.unix_timestamp)?;
+
+    if amount == 0 {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+
+    vault.record_payout(amount, clock.slot)?;
+    stream.record_claim(amount, clock.unix_timestamp)?;
+
+    **stream.to_account_info().lamports.borrow_mut() -= amount;
+    **ctx.accounts.recipient.to_account_info().lamports.borrow_mut() += amount;
+
+    emit!(ClaimStreamEvent {
+        vault: vault.key(),
+        stream: stream.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        claimed: stream.claimed,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//-------------------------------------------------------------------------------
+///
+/// Close Vault Instruction
+///
+/// Requirements:
+/// - Only the vault authority can close the vault
+/// - Verify that vault withdrawals are not paused
+/// - Verify that the vault has no withdraw delay, so closing cannot bypass the timelock
+/// - Verify that no depositor balances or reward reserve are left in the vault
+/// - Verify that no streams are open, they need the vault to be claimed or cancelled
+/// - Count the surplus paid out against the vault's rate limit
+/// - Close the vault account and return all of its lamports to the vault authority
+/// - Emit a close vault event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::CloseVaultEvent;
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        close = vault_authority,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _close_vault(ctx: Context<CloseVault>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    if vault.withdrawals_paused {
+        return Err(VaultError::VaultLocked.into());
+    }
+
+    if vault.withdraw_delay > 0 {
+        return Err(VaultError::WithdrawTimelocked.into());
+    }
+
+    if vault.depositor_balance > 0 {
+        return Err(VaultError::OutstandingDeposits.into());
+    }
+
+    if vault.reward_reserve > 0 {
+        return Err(VaultError::ReservedFunds.into());
+    }
+
+    if vault.open_streams > 0 {
+        return Err(VaultError::OpenStreams.into());
+    }
+
+    // the rent-exempt minimum goes back to the authority as well, but only the
+    // surplus is a withdrawal from the vault's funds
+    let surplus = Vault::surplus_lamports(&vault.to_account_info())?;
+    vault.record_withdrawal(surplus, Clock::get()?.unix_timestamp)?;
+
+    emit!(CloseVaultEvent {
+        vault: vault.key(),
+        vault_authority: ctx.accounts.vault_authority.key(),
+        lamports: vault.to_account_info().lamports(),
+    });
+
+    Ok(())
+}
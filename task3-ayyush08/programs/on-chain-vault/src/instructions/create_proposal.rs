@@ -0,0 +1,70 @@
+//-------------------------------------------------------------------------------
+///
+/// Create Proposal Instruction
+///
+/// Requirements:
+/// - Verify that the vault is in multisig mode
+/// - Only a multisig signer can create a proposal
+/// - Record the proposer's approval on the new proposal
+/// - Emit a create proposal event and an approve proposal event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Proposal, ProposalAction, Vault};
+use crate::errors::VaultError;
+use crate::events::{ApproveProposalEvent, CreateProposalEvent};
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_multisig() @ VaultError::NotMultisig,
+        constraint = vault.is_signer(&proposer.key()) @ VaultError::NotASigner
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [b"proposal", vault.key().as_ref(), vault.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.vault = vault.key();
+    proposal.id = vault.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.bump = ctx.bumps.proposal;
+
+    vault.proposal_count = vault.proposal_count.checked_add(1).ok_or(VaultError::Overflow)?;
+
+    emit!(CreateProposalEvent {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        proposer: proposal.proposer,
+        action: proposal.action.clone(),
+    });
+
+    emit!(ApproveProposalEvent {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        signer: proposal.proposer,
+        approvals: proposal.approvals.len() as u8,
+    });
+
+    Ok(())
+}
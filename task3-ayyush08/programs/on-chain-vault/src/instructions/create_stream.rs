@@ -0,0 +1,101 @@
+//-------------------------------------------------------------------------------
+///
+/// Create Stream Instruction
+///
+/// Requirements:
+/// - Only the vault authority can create a stream
+/// - Verify that the stream rate and cap are positive
+/// - Verify that vault withdrawals are not paused and not timelocked
+/// - Verify that the vault can cover the cap and keeps its rent-exempt minimum
+/// - Verify that the cap leaves depositor balances and the reward reserve covered
+/// - Count the cap against the vault's rate limit, so claims never run into it
+/// - Move the cap from the vault into the stream, the recipient claims it from there
+///   (payouts are added to the vault statistics when they are claimed)
+/// - Count the stream as open on the vault
+/// - Emit a create stream event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Stream, Vault};
+use crate::errors::VaultError;
+use crate::events::CreateStreamEvent;
+
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey)]
+pub struct CreateStream<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        init,
+        payer = vault_authority,
+        space = 8 + Stream::INIT_SPACE,
+        seeds = [b"stream", vault.key().as_ref(), recipient.as_ref()],
+        bump
+    )]
+    pub stream: Account<'info, Stream>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _create_stream(ctx: Context<CreateStream>, recipient: Pubkey, rate: u64, cap: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let stream = &mut ctx.accounts.stream;
+
+    if rate == 0 || cap == 0 {
+        return Err(VaultError::InvalidStream.into());
+    }
+
+    if vault.withdrawals_paused {
+        return Err(VaultError::VaultLocked.into());
+    }
+
+    if vault.withdraw_delay > 0 {
+        return Err(VaultError::WithdrawTimelocked.into());
+    }
+
+    if **vault.to_account_info().lamports.borrow() < cap {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+
+    if Vault::surplus_lamports(&vault.to_account_info())? < cap {
+        return Err(VaultError::BelowRentExemptMinimum.into());
+    }
+
+    if vault.authority_lamports(&vault.to_account_info())? < cap {
+        return Err(VaultError::ReservedFunds.into());
+    }
+
+    let clock = Clock::get()?;
+
+    vault.record_withdrawal(cap, clock.unix_timestamp)?;
+    vault.open_streams = vault.open_streams.checked_add(1).ok_or(VaultError::Overflow)?;
+
+    stream.vault = vault.key();
+    stream.recipient = recipient;
+    stream.rate = rate;
+    stream.cap = cap;
+    stream.claimed = 0;
+    stream.last_claim = clock.unix_timestamp;
+    stream.bump = ctx.bumps.stream;
+
+    **vault.to_account_info().lamports.borrow_mut() -= cap;
+    **stream.to_account_info().lamports.borrow_mut() += cap;
+
+    emit!(CreateStreamEvent {
+        vault: vault.key(),
+        stream: stream.key(),
+        recipient,
+        rate,
+        cap,
+    });
+
+    Ok(())
+}
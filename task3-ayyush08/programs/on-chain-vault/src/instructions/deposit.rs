@@ -3,9 +3,12 @@
 /// TASK: Implement the deposit functionality for the on-chain vault
 /// 
 /// Requirements:
-/// - Verify that the user has enough balance to deposit
-/// - Verify that the vault is not locked
-/// - Transfer lamports from user to vault using CPI (Cross-Program Invocation)
+/// - Verify that vault deposits are not paused
+/// - Verify that the user is allowlisted when the vault allowlist is enabled
+/// - Transfer lamports from user to vault using CPI (Cross-Program Invocation),
+///   the transfer fails if the user cannot cover the deposit
+/// - Accrue pending rewards and credit the deposited lamports to the user's depositor position
+/// - Add the deposit to the vault statistics (total deposited, deposit count, last activity slot)
 /// - Emit a deposit event after successful transfer
 /// 
 ///-------------------------------------------------------------------------------
@@ -13,7 +16,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::system_instruction::transfer;
-use crate::state::Vault;
+use crate::state::{DepositorPosition, Vault};
 use crate::errors::VaultError;
 use crate::events::DepositEvent;
 
@@ -24,10 +27,24 @@ pub struct Deposit<'info> {
     pub user: Signer<'info>,
     #[account(
         mut,
-        seeds = [b"vault", vault.vault_authority.key().as_ref()],
-        bump
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump
     )]
     pub vault: Account<'info,Vault>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + DepositorPosition::INIT_SPACE,
+        seeds = [b"position", vault.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub position: Account<'info, DepositorPosition>,
+    /// CHECK: The user's allowlist PDA, only has to exist when the vault allowlist is enabled
+    #[account(
+        seeds = [b"allowlist", vault.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub allowlist_entry: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -35,15 +52,11 @@ pub fn _deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
     // TODO: Implement deposit functionality
     let vault = &mut ctx.accounts.vault;
     
-    if vault.locked {
+    if vault.deposits_paused {
         return Err(VaultError::VaultLocked.into());
     }
 
-    let user_lamports = ctx.accounts.user.lamports();
-
-    if user_lamports < amount{
-        return Err(VaultError::InsufficientBalance.into());
-    }
+    vault.check_allowlisted(&ctx.accounts.allowlist_entry)?;
 
     let transaction = transfer(
         &ctx.accounts.user.key(),
@@ -59,10 +72,26 @@ pub fn _deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         ]
     )?;
 
+    let vault = &mut ctx.accounts.vault;
+    let position = &mut ctx.accounts.position;
+
+    let clock = Clock::get()?;
+
+    vault.depositor_balance = vault.depositor_balance.checked_add(amount).ok_or(VaultError::Overflow)?;
+    vault.record_deposit(amount, clock.slot)?;
+
+    position.vault = vault.key();
+    position.user = ctx.accounts.user.key();
+    position.bump = ctx.bumps.position;
+    vault.update_reward_index(clock.unix_timestamp)?;
+    position.accrue_rewards(vault.reward_index)?;
+    position.balance = position.balance.checked_add(amount).ok_or(VaultError::Overflow)?;
+
     emit!(DepositEvent {
-        vault: ctx.accounts.vault.key(),
+        vault: vault.key(),
         user: ctx.accounts.user.key(),
         amount,
+        position_balance: position.balance,
     });
 
     Ok(())
@@ -0,0 +1,88 @@
+//-------------------------------------------------------------------------------
+///
+/// Deposit Token Instruction
+///
+/// Requirements:
+/// - Verify that vault deposits are not paused
+/// - Verify that the user is allowlisted when the vault allowlist is enabled
+/// - Create the vault's associated token account for the mint if it does not exist yet
+/// - Transfer tokens from the user's token account to the vault's token account
+///   (works with both Token and Token-2022 mints)
+/// - Emit a deposit token event after successful transfer
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::DepositTokenEvent;
+
+#[derive(Accounts)]
+pub struct DepositToken<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: The user's allowlist PDA, only has to exist when the vault allowlist is enabled
+    #[account(
+        seeds = [b"allowlist", vault.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub allowlist_entry: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _deposit_token(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
+    if ctx.accounts.vault.deposits_paused {
+        return Err(VaultError::VaultLocked.into());
+    }
+
+    ctx.accounts.vault.check_allowlisted(&ctx.accounts.allowlist_entry)?;
+
+    if ctx.accounts.user_token_account.amount < amount {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.vault_token_account.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+
+    transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    emit!(DepositTokenEvent {
+        amount,
+        user: ctx.accounts.user.key(),
+        vault: ctx.accounts.vault.key(),
+        mint: ctx.accounts.mint.key(),
+    });
+
+    Ok(())
+}
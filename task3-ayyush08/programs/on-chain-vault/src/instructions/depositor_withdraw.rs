@@ -0,0 +1,79 @@
+//-------------------------------------------------------------------------------
+///
+/// Depositor Withdraw Instruction
+///
+/// Requirements:
+/// - Verify that vault withdrawals are not paused
+/// - Verify that the depositor's position holds enough lamports
+/// - Transfer lamports from vault back to the depositor
+/// - Accrue pending rewards and debit the withdrawn lamports from the depositor's position
+/// - Add the withdrawal to the vault statistics
+/// - Emit a depositor withdraw event after successful transfer
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{DepositorPosition, Vault};
+use crate::errors::VaultError;
+use crate::events::DepositorWithdrawEvent;
+
+#[derive(Accounts)]
+pub struct DepositorWithdraw<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"position", vault.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        has_one = vault,
+        has_one = user
+    )]
+    pub position: Account<'info, DepositorPosition>,
+}
+
+pub fn _depositor_withdraw(ctx: Context<DepositorWithdraw>, amount: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let position = &mut ctx.accounts.position;
+
+    if vault.withdrawals_paused {
+        return Err(VaultError::VaultLocked.into());
+    }
+
+    if position.balance < amount {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+
+    if **vault.to_account_info().lamports.borrow() < amount {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+
+    if Vault::surplus_lamports(&vault.to_account_info())? < amount {
+        return Err(VaultError::BelowRentExemptMinimum.into());
+    }
+
+    let clock = Clock::get()?;
+
+    vault.update_reward_index(clock.unix_timestamp)?;
+    position.accrue_rewards(vault.reward_index)?;
+    position.balance -= amount;
+    vault.depositor_balance = vault.depositor_balance.checked_sub(amount).ok_or(VaultError::Overflow)?;
+    vault.record_payout(amount, clock.slot)?;
+
+    **vault.to_account_info().lamports.borrow_mut() -= amount;
+    **ctx.accounts.user.to_account_info().lamports.borrow_mut() += amount;
+
+    emit!(DepositorWithdrawEvent {
+        amount,
+        user: ctx.accounts.user.key(),
+        vault: vault.key(),
+        position_balance: position.balance,
+    });
+
+    Ok(())
+}
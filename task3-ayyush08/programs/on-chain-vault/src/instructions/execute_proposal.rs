@@ -0,0 +1,239 @@
+//-------------------------------------------------------------------------------
+///
+/// Execute Proposal Instruction
+///
+/// Requirements:
+/// - Only a multisig signer can execute a proposal
+/// - Verify that the proposal has reached the vault's approval threshold
+///   (approvals from keys that are no longer signers do not count)
+/// - Perform the proposed action and close the proposal to its proposer, withdrawals
+///   (lamports or tokens) only once the vault's withdraw delay has passed since the
+///   proposal was created
+/// - Emit the action's event and an execute proposal event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{Proposal, ProposalAction, Vault};
+use crate::errors::VaultError;
+use crate::events::{ExecuteProposalEvent, ProposeAuthorityEvent, SetGuardianEvent, SetMultisigEvent, SetPauseEvent, SetRewardRateEvent, ToggleLockEvent, WithdrawToEvent, WithdrawTokenToEvent};
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    pub executor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_multisig() @ VaultError::NotMultisig,
+        constraint = vault.is_signer(&executor.key()) @ VaultError::NotASigner
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", vault.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = vault,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Receives the proposal rent, validated by `has_one` on the proposal
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    /// CHECK: Only used by withdraw proposals, validated against the proposal action.
+    /// For token withdrawals this is the recipient's token account
+    #[account(mut)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+    /// The token accounts below are only used by token withdraw proposals
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+/// Timestamp from which a withdraw proposal created at `created_at` can be executed.
+fn withdraw_unlock_timestamp(vault: &Vault, created_at: i64) -> Result<i64> {
+    created_at.checked_add(vault.withdraw_delay).ok_or_else(|| VaultError::Overflow.into())
+}
+
+pub fn _execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let proposal = &ctx.accounts.proposal;
+
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approver| vault.is_signer(approver))
+        .count();
+
+    if approvals < vault.threshold as usize {
+        return Err(VaultError::InsufficientApprovals.into());
+    }
+
+    match proposal.action.clone() {
+        ProposalAction::Withdraw { amount, recipient } => {
+            let recipient_info = match &ctx.accounts.recipient {
+                Some(account) if account.key() == recipient => account.to_account_info(),
+                _ => return Err(VaultError::InvalidRecipient.into()),
+            };
+
+            if vault.withdrawals_paused {
+                return Err(VaultError::VaultLocked.into());
+            }
+
+            let clock = Clock::get()?;
+            let now = clock.unix_timestamp;
+
+            if now < withdraw_unlock_timestamp(vault, proposal.created_at)? {
+                return Err(VaultError::WithdrawNotReady.into());
+            }
+
+            if **vault.to_account_info().lamports.borrow() < amount {
+                return Err(VaultError::InsufficientBalance.into());
+            }
+
+            if Vault::surplus_lamports(&vault.to_account_info())? < amount {
+                return Err(VaultError::BelowRentExemptMinimum.into());
+            }
+
+            if vault.authority_lamports(&vault.to_account_info())? < amount {
+                return Err(VaultError::ReservedFunds.into());
+            }
+
+            vault.record_withdrawal(amount, now)?;
+            vault.record_payout(amount, clock.slot)?;
+
+            **vault.to_account_info().lamports.borrow_mut() -= amount;
+            **recipient_info.lamports.borrow_mut() += amount;
+
+            emit!(WithdrawToEvent {
+                amount,
+                vault_authority: vault.vault_authority,
+                vault: vault.key(),
+                recipient,
+            });
+        }
+        ProposalAction::WithdrawToken { mint, amount, recipient } => {
+            let (Some(mint_account), Some(vault_token_account), Some(token_program)) = (
+                &ctx.accounts.mint,
+                &ctx.accounts.vault_token_account,
+                &ctx.accounts.token_program,
+            ) else {
+                return Err(VaultError::InvalidTokenAccounts.into());
+            };
+
+            if mint_account.key() != mint || vault_token_account.owner != vault.key() {
+                return Err(VaultError::InvalidTokenAccounts.into());
+            }
+
+            let recipient_info = match &ctx.accounts.recipient {
+                Some(account) if account.key() == recipient => account.to_account_info(),
+                _ => return Err(VaultError::InvalidRecipient.into()),
+            };
+
+            if vault.withdrawals_paused {
+                return Err(VaultError::VaultLocked.into());
+            }
+
+            if Clock::get()?.unix_timestamp < withdraw_unlock_timestamp(vault, proposal.created_at)? {
+                return Err(VaultError::WithdrawNotReady.into());
+            }
+
+            if vault_token_account.amount < amount {
+                return Err(VaultError::InsufficientBalance.into());
+            }
+
+            let id = vault.id_seed();
+            let signer_seeds: &[&[&[u8]]] = &[&[b"vault", vault.creator.as_ref(), &id, &[vault.bump]]];
+
+            let cpi_accounts = TransferChecked {
+                from: vault_token_account.to_account_info(),
+                mint: mint_account.to_account_info(),
+                to: recipient_info,
+                authority: vault.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
+
+            transfer_checked(cpi_ctx, amount, mint_account.decimals)?;
+
+            emit!(WithdrawTokenToEvent {
+                amount,
+                vault_authority: vault.vault_authority,
+                vault: vault.key(),
+                mint,
+                recipient,
+            });
+        }
+        ProposalAction::ToggleLock => {
+            let locked = !vault.is_locked();
+            vault.set_locked(locked);
+
+            emit!(ToggleLockEvent {
+                vault: vault.key(),
+                vault_authority: vault.vault_authority,
+                locked,
+                deposits_paused: vault.deposits_paused,
+                withdrawals_paused: vault.withdrawals_paused,
+            });
+        }
+        ProposalAction::SetPause { deposits_paused, withdrawals_paused } => {
+            vault.deposits_paused = deposits_paused;
+            vault.withdrawals_paused = withdrawals_paused;
+
+            emit!(SetPauseEvent {
+                vault: vault.key(),
+                vault_authority: vault.vault_authority,
+                deposits_paused,
+                withdrawals_paused,
+            });
+        }
+        ProposalAction::ProposeAuthority { new_authority } => {
+            vault.pending_authority = Some(new_authority);
+
+            emit!(ProposeAuthorityEvent {
+                vault: vault.key(),
+                vault_authority: vault.vault_authority,
+                pending_authority: new_authority,
+            });
+        }
+        ProposalAction::SetGuardian { guardian } => {
+            vault.guardian = guardian;
+
+            emit!(SetGuardianEvent {
+                vault: vault.key(),
+                vault_authority: vault.vault_authority,
+                guardian,
+            });
+        }
+        ProposalAction::SetRewardRate { reward_rate_bps } => {
+            vault.update_reward_index(Clock::get()?.unix_timestamp)?;
+            vault.reward_rate_bps = reward_rate_bps;
+
+            emit!(SetRewardRateEvent {
+                vault: vault.key(),
+                vault_authority: vault.vault_authority,
+                reward_rate_bps,
+            });
+        }
+        ProposalAction::SetSigners { signers, threshold } => {
+            vault.set_signers(signers, threshold)?;
+
+            emit!(SetMultisigEvent {
+                vault: vault.key(),
+                signers: vault.signers.clone(),
+                threshold: vault.threshold,
+            });
+        }
+    }
+
+    emit!(ExecuteProposalEvent {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        executor: ctx.accounts.executor.key(),
+        action: proposal.action.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//-------------------------------------------------------------------------------
+///
+/// Execute Withdraw Instruction
+///
+/// Requirements:
+/// - Only the vault authority can execute a pending withdrawal
+/// - Verify that vault withdrawals are not paused
+/// - Verify that the withdrawal's unlock timestamp has passed
+/// - Verify that the vault has enough balance to withdraw
+/// - Verify that the vault keeps its rent-exempt minimum after the withdrawal
+/// - Verify that the withdrawal leaves depositor balances and the reward reserve covered
+/// - Count the withdrawal against the vault's rate limit and add it to the vault statistics
+/// - Transfer lamports from vault to vault authority and close the pending withdrawal
+/// - Emit a withdraw event after successful transfer
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{PendingWithdrawal, Vault};
+use crate::errors::VaultError;
+use crate::events::WithdrawEvent;
+
+#[derive(Accounts)]
+pub struct ExecuteWithdraw<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        close = vault_authority,
+        seeds = [b"pending_withdrawal", vault.key().as_ref()],
+        bump = pending_withdrawal.bump,
+        has_one = vault
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+}
+
+pub fn _execute_withdraw(ctx: Context<ExecuteWithdraw>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let pending_withdrawal = &ctx.accounts.pending_withdrawal;
+    let amount = pending_withdrawal.amount;
+
+    if vault.withdrawals_paused {
+        return Err(VaultError::VaultLocked.into());
+    }
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    if now < pending_withdrawal.unlock_timestamp {
+        return Err(VaultError::WithdrawNotReady.into());
+    }
+
+    if **vault.to_account_info().lamports.borrow() < amount {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+
+    if Vault::surplus_lamports(&vault.to_account_info())? < amount {
+        return Err(VaultError::BelowRentExemptMinimum.into());
+    }
+
+    if vault.authority_lamports(&vault.to_account_info())? < amount {
+        return Err(VaultError::ReservedFunds.into());
+    }
+
+    vault.record_withdrawal(amount, now)?;
+    vault.record_payout(amount, clock.slot)?;
+
+    **vault.to_account_info().lamports.borrow_mut() -= amount;
+    **ctx.accounts.vault_authority.to_account_info().lamports.borrow_mut() += amount;
+
+    emit!(WithdrawEvent {
+        amount,
+        vault_authority: ctx.accounts.vault_authority.key(),
+        vault: vault.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//-------------------------------------------------------------------------------
+///
+/// Fund Rewards Instruction
+///
+/// Requirements:
+/// - Transfer lamports from the funder to the vault using CPI
+/// - Add the lamports to the vault's reward reserve
+/// - Emit a fund rewards event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::FundRewardsEvent;
+
+#[derive(Accounts)]
+pub struct FundRewards<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _fund_rewards(ctx: Context<FundRewards>, amount: u64) -> Result<()> {
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.funder.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+
+    transfer(cpi_ctx, amount)?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.reward_reserve = vault.reward_reserve.checked_add(amount).ok_or(VaultError::Overflow)?;
+
+    emit!(FundRewardsEvent {
+        amount,
+        funder: ctx.accounts.funder.key(),
+        vault: vault.key(),
+        reward_reserve: vault.reward_reserve,
+    });
+
+    Ok(())
+}
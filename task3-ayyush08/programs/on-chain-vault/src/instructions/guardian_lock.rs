@@ -0,0 +1,40 @@
+//-------------------------------------------------------------------------------
+///
+/// Guardian Lock Instruction
+///
+/// Requirements:
+/// - Only the vault guardian can call this instruction
+/// - Pause both deposits and withdrawals (the guardian can never unlock)
+/// - Emit a guardian lock event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::GuardianLockEvent;
+
+#[derive(Accounts)]
+pub struct GuardianLock<'info> {
+    pub guardian: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.guardian == Some(guardian.key()) @ VaultError::NotGuardian
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _guardian_lock(ctx: Context<GuardianLock>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.set_locked(true);
+
+    emit!(GuardianLockEvent {
+        vault: vault.key(),
+        guardian: ctx.accounts.guardian.key(),
+    });
+
+    Ok(())
+}
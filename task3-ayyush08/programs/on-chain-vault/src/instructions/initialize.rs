@@ -16,10 +16,12 @@
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
-use crate::state::Vault;
+use crate::state::{Vault, VAULT_VERSION};
+use crate::errors::VaultError;
 use crate::events::InitializeVaultEvent;
 
 #[derive(Accounts)]
+#[instruction(id: u64)]
 pub struct InitializeVault<'info> {
     #[account(mut)]
     pub vault_authority: Signer<'info>,
@@ -28,23 +30,35 @@ pub struct InitializeVault<'info> {
         payer = vault_authority, 
         // space = discriminant + account size
         space = 8 + Vault::INIT_SPACE,
-        seeds = [b"vault", vault_authority.key().as_ref()],
+        // an authority can open several vaults, told apart by their id
+        seeds = [b"vault", vault_authority.key().as_ref(), id.to_le_bytes().as_ref()],
         bump
     )]
     pub vault: Account<'info, Vault>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn _init_vault(ctx: Context<InitializeVault>, locked: bool) -> Result<()> {
+pub fn _init_vault(ctx: Context<InitializeVault>, id: u64, locked: bool, withdraw_delay: i64) -> Result<()> {
+  if withdraw_delay < 0 {
+    return Err(VaultError::InvalidWithdrawDelay.into());
+  }
+
   let vault = &mut ctx.accounts.vault;
 
   vault.vault_authority = ctx.accounts.vault_authority.key();
-  vault.locked = locked;
+  vault.creator = ctx.accounts.vault_authority.key();
+  vault.id = id;
+  vault.bump = ctx.bumps.vault;
+  vault.set_locked(locked);
+  vault.withdraw_delay = withdraw_delay;
+  vault.version = VAULT_VERSION;
 
   emit!(InitializeVaultEvent {
     vault: vault.key(),
     vault_authority: vault.vault_authority,
+    id,
     locked,
+    withdraw_delay,
   });
 
   Ok(())
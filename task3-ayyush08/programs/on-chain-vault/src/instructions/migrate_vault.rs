@@ -0,0 +1,118 @@
+//-------------------------------------------------------------------------------
+///
+/// Migrate Vault Instruction
+///
+/// Requirements:
+/// - Grow a vault created with an older, shorter layout to `8 + Vault::INIT_SPACE`,
+///   the payer covers the extra rent and the new bytes are zeroed
+/// - Verify that the account is a vault of this program
+/// - Convert vaults created by the original program (`vault_authority` and `locked`
+///   only, at `[b"vault", vault_authority]`): the authority becomes the creator, the
+///   vault keeps its legacy address and `locked` pauses both directions
+/// - Fail if the vault already uses the current layout version
+/// - Set the vault's layout version to `VAULT_VERSION`
+/// - Emit a migrate vault event
+///
+/// Migration does not change what the vault holds or who controls it, so anyone
+/// can pay for it.
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::{Vault, ORIGINAL_VAULT_SPACE, VAULT_VERSION};
+use crate::errors::VaultError;
+use crate::events::MigrateVaultEvent;
+
+#[derive(Accounts)]
+pub struct MigrateVault<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: An older layout does not deserialize as `Vault` until it has been
+    /// reallocated, the discriminator is checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub vault: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _migrate_vault(ctx: Context<MigrateVault>) -> Result<()> {
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let old_len = vault_info.data_len();
+    let new_len = 8 + Vault::INIT_SPACE;
+    let rent = Rent::get()?;
+
+    // the original layout has to be read before the account is resized, its
+    // `locked` byte overlaps the first byte of `creator`
+    let original = if old_len == ORIGINAL_VAULT_SPACE {
+        let data = vault_info.try_borrow_data()?;
+
+        if &data[..8] != Vault::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+
+        let vault_authority = Pubkey::try_from(&data[8..40]).map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+        let (address, bump) = Pubkey::find_program_address(&[b"vault", vault_authority.as_ref()], &crate::ID);
+
+        if address != vault_info.key() {
+            return Err(ErrorCode::ConstraintSeeds.into());
+        }
+
+        Some((vault_authority, data[40] != 0, bump))
+    } else {
+        None
+    };
+
+    // the payer covers the rent for the bytes the new layout adds, whatever
+    // the vault holds on top of its rent minimum is left untouched
+    let rent_due = rent.minimum_balance(new_len).saturating_sub(rent.minimum_balance(old_len));
+    if rent_due > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: vault_info.clone(),
+            },
+        );
+        transfer(cpi_context, rent_due)?;
+    }
+
+    if old_len < new_len {
+        vault_info.resize(new_len)?;
+    }
+
+    let mut data = vault_info.try_borrow_mut_data()?;
+
+    if original.is_some() {
+        data[40] = 0;
+    }
+
+    let mut vault = Vault::try_deserialize(&mut &data[..])?;
+
+    if let Some((vault_authority, locked, bump)) = original {
+        vault.creator = vault_authority;
+        vault.id = 0;
+        vault.bump = bump;
+        vault.legacy_address = true;
+        vault.set_locked(locked);
+    }
+
+    let previous_version = vault.version;
+
+    if previous_version >= VAULT_VERSION {
+        return Err(VaultError::AlreadyMigrated.into());
+    }
+
+    // Fields added in later versions are converted here, until then they read
+    // as zero from the reallocated bytes
+    vault.version = VAULT_VERSION;
+    vault.try_serialize(&mut &mut data[..])?;
+
+    emit!(MigrateVaultEvent {
+        vault: ctx.accounts.vault.key(),
+        payer: ctx.accounts.payer.key(),
+        previous_version,
+        version: VAULT_VERSION,
+    });
+
+    Ok(())
+}
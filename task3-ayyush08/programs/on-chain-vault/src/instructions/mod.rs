@@ -1,9 +1,67 @@
 mod initialize;
 mod deposit;
 mod withdraw;
+mod withdraw_all;
+mod withdraw_to_many;
 mod toggle_lock;
+mod set_pause;
+mod deposit_token;
+mod withdraw_token;
+mod depositor_withdraw;
+mod request_withdraw;
+mod execute_withdraw;
+mod cancel_withdraw;
+mod set_multisig;
+mod create_proposal;
+mod approve_proposal;
+mod execute_proposal;
+mod close_vault;
+mod propose_authority;
+mod accept_authority;
+mod set_withdraw_limit;
+mod set_allowlist_enabled;
+mod add_to_allowlist;
+mod remove_from_allowlist;
+mod set_guardian;
+mod guardian_lock;
+mod set_reward_rate;
+mod fund_rewards;
+mod claim_rewards;
+mod migrate_vault;
+mod create_stream;
+mod claim_stream;
+mod cancel_stream;
 
 pub use initialize::*;
 pub use deposit::*;
 pub use withdraw::*;
-pub use toggle_lock::*;
\ No newline at end of file
+pub use withdraw_all::*;
+pub use withdraw_to_many::*;
+pub use toggle_lock::*;
+pub use set_pause::*;
+pub use deposit_token::*;
+pub use withdraw_token::*;
+pub use depositor_withdraw::*;
+pub use request_withdraw::*;
+pub use execute_withdraw::*;
+pub use cancel_withdraw::*;
+pub use set_multisig::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use execute_proposal::*;
+pub use close_vault::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use set_withdraw_limit::*;
+pub use set_allowlist_enabled::*;
+pub use add_to_allowlist::*;
+pub use remove_from_allowlist::*;
+pub use set_guardian::*;
+pub use guardian_lock::*;
+pub use set_reward_rate::*;
+pub use fund_rewards::*;
+pub use claim_rewards::*;
+pub use migrate_vault::*;
+pub use create_stream::*;
+pub use claim_stream::*;
+pub use cancel_stream::*;
\ No newline at end of file
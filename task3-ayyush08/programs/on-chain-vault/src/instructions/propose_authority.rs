@@ -0,0 +1,43 @@
+//-------------------------------------------------------------------------------
+///
+/// Propose Authority Instruction
+///
+/// Requirements:
+/// - Only the vault authority can propose a new authority
+/// - Store the proposed key as the vault's pending authority (the transfer
+///   completes only once the new key calls accept_authority)
+/// - Emit a propose authority event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::ProposeAuthorityEvent;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.pending_authority = Some(new_authority);
+
+    emit!(ProposeAuthorityEvent {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
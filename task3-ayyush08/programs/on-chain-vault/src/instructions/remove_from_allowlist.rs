@@ -0,0 +1,45 @@
+//-------------------------------------------------------------------------------
+///
+/// Remove From Allowlist Instruction
+///
+/// Requirements:
+/// - Only the vault authority can remove depositors from the allowlist
+/// - Close the depositor's allowlist entry and refund its rent to the vault authority
+/// - Emit a remove from allowlist event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{AllowlistEntry, Vault};
+use crate::errors::VaultError;
+use crate::events::RemoveFromAllowlistEvent;
+
+#[derive(Accounts)]
+pub struct RemoveFromAllowlist<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        close = vault_authority,
+        seeds = [b"allowlist", vault.key().as_ref(), allowlist_entry.depositor.as_ref()],
+        bump = allowlist_entry.bump,
+        has_one = vault
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+}
+
+pub fn _remove_from_allowlist(ctx: Context<RemoveFromAllowlist>) -> Result<()> {
+    emit!(RemoveFromAllowlistEvent {
+        vault: ctx.accounts.vault.key(),
+        depositor: ctx.accounts.allowlist_entry.depositor,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+//-------------------------------------------------------------------------------
+///
+/// Request Withdraw Instruction
+///
+/// Requirements:
+/// - Only the vault authority can request a withdrawal
+/// - Create a pending withdrawal that unlocks after the vault's withdraw delay
+/// - Emit a request withdraw event with the unlock timestamp
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{PendingWithdrawal, Vault};
+use crate::errors::VaultError;
+use crate::events::RequestWithdrawEvent;
+
+#[derive(Accounts)]
+pub struct RequestWithdraw<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        init,
+        payer = vault_authority,
+        space = 8 + PendingWithdrawal::INIT_SPACE,
+        seeds = [b"pending_withdrawal", vault.key().as_ref()],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _request_withdraw(ctx: Context<RequestWithdraw>, amount: u64) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let pending_withdrawal = &mut ctx.accounts.pending_withdrawal;
+
+    let unlock_timestamp = Clock::get()?
+        .unix_timestamp
+        .checked_add(vault.withdraw_delay)
+        .ok_or(VaultError::Overflow)?;
+
+    pending_withdrawal.vault = vault.key();
+    pending_withdrawal.amount = amount;
+    pending_withdrawal.unlock_timestamp = unlock_timestamp;
+    pending_withdrawal.bump = ctx.bumps.pending_withdrawal;
+
+    emit!(RequestWithdrawEvent {
+        amount,
+        vault_authority: ctx.accounts.vault_authority.key(),
+        vault: vault.key(),
+        unlock_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//-------------------------------------------------------------------------------
+///
+/// Set Allowlist Enabled Instruction
+///
+/// Requirements:
+/// - Only the vault authority can turn the deposit allowlist on or off
+/// - Emit a set allowlist enabled event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::SetAllowlistEnabledEvent;
+
+#[derive(Accounts)]
+pub struct SetAllowlistEnabled<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_allowlist_enabled(ctx: Context<SetAllowlistEnabled>, allowlist_enabled: bool) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.allowlist_enabled = allowlist_enabled;
+
+    emit!(SetAllowlistEnabledEvent {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        allowlist_enabled,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//-------------------------------------------------------------------------------
+///
+/// Set Guardian Instruction
+///
+/// Requirements:
+/// - Only the vault authority can set or clear the guardian
+/// - Emit a set guardian event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::SetGuardianEvent;
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_guardian(ctx: Context<SetGuardian>, guardian: Option<Pubkey>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.guardian = guardian;
+
+    emit!(SetGuardianEvent {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        guardian,
+    });
+
+    Ok(())
+}
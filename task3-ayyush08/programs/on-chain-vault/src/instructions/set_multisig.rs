@@ -0,0 +1,48 @@
+//-------------------------------------------------------------------------------
+///
+/// Set Multisig Instruction
+///
+/// Requirements:
+/// - Only the vault authority can switch a single authority vault to multisig mode
+/// - Validate the signer set and the approval threshold
+/// - Emit a set multisig event
+///
+/// Once the vault is in multisig mode, signer changes go through proposals.
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::SetMultisigEvent;
+
+#[derive(Accounts)]
+pub struct SetMultisig<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_multisig(ctx: Context<SetMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    if threshold == 0 {
+        return Err(VaultError::InvalidThreshold.into());
+    }
+
+    vault.set_signers(signers, threshold)?;
+
+    emit!(SetMultisigEvent {
+        vault: vault.key(),
+        signers: vault.signers.clone(),
+        threshold: vault.threshold,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//-------------------------------------------------------------------------------
+///
+/// Set Pause Instruction
+///
+/// Requirements:
+/// - Only the vault authority can pause or resume the vault
+/// - Set deposits and withdrawals paused flags explicitly and independently,
+///   e.g. freeze outflows during an incident while still accepting deposits
+/// - Emit a set pause event with both flags
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::SetPauseEvent;
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_pause(ctx: Context<SetPause>, deposits_paused: bool, withdrawals_paused: bool) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.deposits_paused = deposits_paused;
+    vault.withdrawals_paused = withdrawals_paused;
+
+    emit!(SetPauseEvent {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        deposits_paused,
+        withdrawals_paused,
+    });
+
+    Ok(())
+}
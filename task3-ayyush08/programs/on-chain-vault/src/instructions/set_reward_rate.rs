@@ -0,0 +1,44 @@
+//-------------------------------------------------------------------------------
+///
+/// Set Reward Rate Instruction
+///
+/// Requirements:
+/// - Only the vault authority can change the reward rate
+/// - The rate is in basis points of the depositor balance per second, 0 disables rewards
+/// - Advance the vault's reward index first, so the new rate only applies from now on
+/// - Emit a set reward rate event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::SetRewardRateEvent;
+
+#[derive(Accounts)]
+pub struct SetRewardRate<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_reward_rate(ctx: Context<SetRewardRate>, reward_rate_bps: u16) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.update_reward_index(Clock::get()?.unix_timestamp)?;
+    vault.reward_rate_bps = reward_rate_bps;
+
+    emit!(SetRewardRateEvent {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        reward_rate_bps,
+    });
+
+    Ok(())
+}
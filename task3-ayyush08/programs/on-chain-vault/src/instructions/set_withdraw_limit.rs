@@ -0,0 +1,51 @@
+//-------------------------------------------------------------------------------
+///
+/// Set Withdraw Limit Instruction
+///
+/// Requirements:
+/// - Only the vault authority can change the withdraw limit
+/// - A limit of 0 disables rate limiting, otherwise the window must be positive
+/// - Start a fresh rate limit window with the new settings
+/// - Emit a set withdraw limit event
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::SetWithdrawLimitEvent;
+
+#[derive(Accounts)]
+pub struct SetWithdrawLimit<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_withdraw_limit(ctx: Context<SetWithdrawLimit>, withdraw_limit: u64, limit_window: i64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    if withdraw_limit > 0 && limit_window <= 0 {
+        return Err(VaultError::InvalidLimitWindow.into());
+    }
+
+    vault.withdraw_limit = withdraw_limit;
+    vault.limit_window = limit_window;
+    vault.window_start = Clock::get()?.unix_timestamp;
+    vault.window_withdrawn = 0;
+
+    emit!(SetWithdrawLimitEvent {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        withdraw_limit,
+        limit_window,
+    });
+
+    Ok(())
+}
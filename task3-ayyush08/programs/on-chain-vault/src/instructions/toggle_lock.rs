@@ -4,6 +4,9 @@
 /// 
 /// Requirements:
 /// - Toggle the locked state of the vault (locked becomes unlocked, unlocked becomes locked)
+///   A vault counts as locked when both deposits and withdrawals are paused,
+///   toggling pauses or resumes both directions together (see set_pause for
+///   pausing them independently)
 /// - Only the vault authority should be able to toggle the lock
 /// - Emit a toggle lock event after successful state change
 /// 
@@ -11,6 +14,7 @@
 
 use anchor_lang::prelude::*;
 use crate::state::Vault;
+use crate::errors::VaultError;
 use crate::events::ToggleLockEvent;
 
 #[derive(Accounts)]
@@ -20,9 +24,10 @@ pub struct ToggleLock<'info> {
     pub vault_authority: Signer<'info>,
     #[account(
         mut,    
-        seeds = [b"vault", vault_authority.key().as_ref()],
-        bump,
-        constraint = vault.vault_authority == vault_authority.key() @ ProgramError::IllegalOwner
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
     )]
     pub vault: Account<'info, Vault>,
     pub system_program: Program<'info, System>,
@@ -34,12 +39,15 @@ pub fn _toggle_lock(ctx: Context<ToggleLock>) -> Result<()> {
 
     
 
-    vault.locked = !vault.locked;
+    let locked = !vault.is_locked();
+    vault.set_locked(locked);
 
     emit!(ToggleLockEvent {
         vault: vault.key(),
         vault_authority: vault.vault_authority,
-        locked: vault.locked,
+        locked,
+        deposits_paused: vault.deposits_paused,
+        withdrawals_paused: vault.withdrawals_paused,
     });
     Ok(())
 }
\ No newline at end of file
@@ -3,16 +3,19 @@
 /// TASK: Implement the withdraw functionality for the on-chain vault
 /// 
 /// Requirements:
-/// - Verify that the vault is not locked
+/// - Verify that vault withdrawals are not paused
+/// - Verify that the vault has no withdraw delay (timelocked vaults go through
+///   request_withdraw / execute_withdraw)
 /// - Verify that the vault has enough balance to withdraw
+/// - Verify that the vault keeps its rent-exempt minimum after the withdrawal
+/// - Verify that the withdrawal leaves depositor balances and the reward reserve covered
+/// - Count the withdrawal against the vault's rate limit and add it to the vault statistics
 /// - Transfer lamports from vault to vault authority
 /// - Emit a withdraw event after successful transfer
 /// 
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program::invoke;
-use anchor_lang::solana_program::system_instruction::transfer;
 use crate::state::Vault;
 use crate::errors::VaultError;
 use crate::events::WithdrawEvent;
@@ -24,8 +27,10 @@ pub struct Withdraw<'info> {
     pub vault_authority: Signer<'info>,
     #[account(
         mut,
-        seeds = [b"vault", vault_authority.key().as_ref()],
-        bump
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
     )]
     pub vault: Account<'info, Vault>,
     pub system_program: Program<'info, System>,
@@ -36,14 +41,31 @@ pub fn _withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
     
     
 
-    if vault.locked {
+    if vault.withdrawals_paused {
         return Err(VaultError::VaultLocked.into());
     }
 
+    if vault.withdraw_delay > 0 {
+        return Err(VaultError::WithdrawTimelocked.into());
+    }
+
     if **vault.to_account_info().lamports.borrow() < amount {
         return Err(VaultError::InsufficientBalance.into());
     }
 
+    if Vault::surplus_lamports(&vault.to_account_info())? < amount {
+        return Err(VaultError::BelowRentExemptMinimum.into());
+    }
+
+    if vault.authority_lamports(&vault.to_account_info())? < amount {
+        return Err(VaultError::ReservedFunds.into());
+    }
+
+    let clock = Clock::get()?;
+
+    vault.record_withdrawal(amount, clock.unix_timestamp)?;
+    vault.record_payout(amount, clock.slot)?;
+
     **vault.to_account_info().lamports.borrow_mut() -= amount;
     **ctx.accounts.vault_authority.to_account_info().lamports.borrow_mut() += amount;
 
@@ -0,0 +1,25 @@
+//-------------------------------------------------------------------------------
+///
+/// Withdraw All Instruction
+///
+/// Requirements:
+/// - Same accounts and checks as withdraw
+/// - Withdraw exactly the lamports held above the vault's rent-exempt minimum,
+///   depositor balances and reward reserve
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::errors::VaultError;
+use super::withdraw::{_withdraw, Withdraw};
+
+pub fn _withdraw_all(ctx: Context<Withdraw>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let amount = vault.authority_lamports(&vault.to_account_info())?;
+
+    if amount == 0 {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+
+    _withdraw(ctx, amount)
+}
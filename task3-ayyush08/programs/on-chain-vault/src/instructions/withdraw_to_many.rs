@@ -0,0 +1,78 @@
+//-------------------------------------------------------------------------------
+///
+/// Withdraw To Many Instruction
+///
+/// Requirements:
+/// - Same accounts and checks as withdraw, applied to the total payout
+/// - Recipients are passed as writable remaining accounts, one per amount
+/// - Sum the amounts with checked arithmetic
+/// - Transfer lamports from vault to every recipient
+/// - Emit one withdraw to event per payout
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::WithdrawToEvent;
+use super::withdraw::Withdraw;
+
+pub fn _withdraw_to_many<'info>(
+    ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let recipients = ctx.remaining_accounts;
+
+    if amounts.is_empty() || amounts.len() != recipients.len() {
+        return Err(VaultError::RecipientMismatch.into());
+    }
+
+    if vault.withdrawals_paused {
+        return Err(VaultError::VaultLocked.into());
+    }
+
+    if vault.withdraw_delay > 0 {
+        return Err(VaultError::WithdrawTimelocked.into());
+    }
+
+    let total = amounts
+        .iter()
+        .try_fold(0u64, |total, amount| total.checked_add(*amount))
+        .ok_or(VaultError::Overflow)?;
+
+    if **vault.to_account_info().lamports.borrow() < total {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+
+    if Vault::surplus_lamports(&vault.to_account_info())? < total {
+        return Err(VaultError::BelowRentExemptMinimum.into());
+    }
+
+    if vault.authority_lamports(&vault.to_account_info())? < total {
+        return Err(VaultError::ReservedFunds.into());
+    }
+
+    let clock = Clock::get()?;
+
+    vault.record_withdrawal(total, clock.unix_timestamp)?;
+    vault.record_payout(total, clock.slot)?;
+
+    for (recipient, amount) in recipients.iter().zip(amounts) {
+        if !recipient.is_writable {
+            return Err(VaultError::InvalidRecipient.into());
+        }
+
+        **vault.to_account_info().lamports.borrow_mut() -= amount;
+        **recipient.lamports.borrow_mut() += amount;
+
+        emit!(WithdrawToEvent {
+            amount,
+            vault_authority: ctx.accounts.vault_authority.key(),
+            vault: vault.key(),
+            recipient: recipient.key(),
+        });
+    }
+
+    Ok(())
+}
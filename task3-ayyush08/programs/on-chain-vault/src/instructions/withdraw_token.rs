@@ -0,0 +1,94 @@
+//-------------------------------------------------------------------------------
+///
+/// Withdraw Token Instruction
+///
+/// Requirements:
+/// - Verify that vault withdrawals are not paused
+/// - Verify that the vault has no withdraw delay, so a timelock also covers tokens
+/// - Verify that the vault's token account holds enough tokens
+/// - Transfer tokens from the vault's token account to the vault authority's
+///   associated token account, signed by the vault PDA
+/// - Emit a withdraw token event after successful transfer
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::WithdrawTokenEvent;
+
+#[derive(Accounts)]
+pub struct WithdrawToken<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.id_seed().as_ref()],
+        bump = vault.bump,
+        has_one = vault_authority @ VaultError::Unauthorized,
+        constraint = !vault.is_multisig() @ VaultError::MultisigRequired
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = vault_authority,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _withdraw_token(ctx: Context<WithdrawToken>, amount: u64) -> Result<()> {
+    if ctx.accounts.vault.withdrawals_paused {
+        return Err(VaultError::VaultLocked.into());
+    }
+
+    if ctx.accounts.vault.withdraw_delay > 0 {
+        return Err(VaultError::WithdrawTimelocked.into());
+    }
+
+    if ctx.accounts.vault_token_account.amount < amount {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+
+    let vault = &ctx.accounts.vault;
+    let id = vault.id_seed();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"vault", vault.creator.as_ref(), &id, &[vault.bump]]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.vault_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.authority_token_account.to_account_info(),
+        authority: ctx.accounts.vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+
+    transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    emit!(WithdrawTokenEvent {
+        amount,
+        vault_authority: ctx.accounts.vault_authority.key(),
+        vault: ctx.accounts.vault.key(),
+        mint: ctx.accounts.mint.key(),
+    });
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-#![allow(unexpected_cfgs)]
+#![allow(unexpected_cfgs, deprecated, clippy::empty_line_after_doc_comments)]
 
 //===============================================================================
 ///
@@ -25,11 +25,12 @@
 
 use anchor_lang::prelude::*;
 mod instructions;
-mod state;
-mod errors;
-mod events;
+pub mod state;
+pub mod errors;
+pub mod events;
 
 use instructions::*;
+use state::ProposalAction;
 
 declare_id!("ARmiAGe6oAEq5BKguHydD3zt2n5PkV2Q5PLA1McuMkJT");
 
@@ -37,8 +38,8 @@ declare_id!("ARmiAGe6oAEq5BKguHydD3zt2n5PkV2Q5PLA1McuMkJT");
 pub mod on_chain_vault {
     use super::*;
 
-    pub fn init_vault(ctx: Context<InitializeVault>, locked: bool) -> Result<()> {
-      _init_vault(ctx, locked)
+    pub fn init_vault(ctx: Context<InitializeVault>, id: u64, locked: bool, withdraw_delay: i64) -> Result<()> {
+      _init_vault(ctx, id, locked, withdraw_delay)
     }
 
     pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
@@ -49,7 +50,123 @@ pub mod on_chain_vault {
       _withdraw(ctx, amount)
     }
 
+    pub fn withdraw_all(ctx: Context<Withdraw>) -> Result<()> {
+      _withdraw_all(ctx)
+    }
+
+    pub fn withdraw_to_many<'info>(ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>, amounts: Vec<u64>) -> Result<()> {
+      _withdraw_to_many(ctx, amounts)
+    }
+
     pub fn toggle_lock(ctx: Context<ToggleLock>) -> Result<()> {
       _toggle_lock(ctx)
     }
+
+    pub fn set_pause(ctx: Context<SetPause>, deposits_paused: bool, withdrawals_paused: bool) -> Result<()> {
+      _set_pause(ctx, deposits_paused, withdrawals_paused)
+    }
+
+    pub fn deposit_token(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
+      _deposit_token(ctx, amount)
+    }
+
+    pub fn withdraw_token(ctx: Context<WithdrawToken>, amount: u64) -> Result<()> {
+      _withdraw_token(ctx, amount)
+    }
+
+    pub fn depositor_withdraw(ctx: Context<DepositorWithdraw>, amount: u64) -> Result<()> {
+      _depositor_withdraw(ctx, amount)
+    }
+
+    pub fn request_withdraw(ctx: Context<RequestWithdraw>, amount: u64) -> Result<()> {
+      _request_withdraw(ctx, amount)
+    }
+
+    pub fn execute_withdraw(ctx: Context<ExecuteWithdraw>) -> Result<()> {
+      _execute_withdraw(ctx)
+    }
+
+    pub fn cancel_withdraw(ctx: Context<CancelWithdraw>) -> Result<()> {
+      _cancel_withdraw(ctx)
+    }
+
+    pub fn set_multisig(ctx: Context<SetMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+      _set_multisig(ctx, signers, threshold)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+      _create_proposal(ctx, action)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+      _approve_proposal(ctx)
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+      _execute_proposal(ctx)
+    }
+
+    pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+      _close_vault(ctx)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+      _propose_authority(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+      _accept_authority(ctx)
+    }
+
+    pub fn set_withdraw_limit(ctx: Context<SetWithdrawLimit>, withdraw_limit: u64, limit_window: i64) -> Result<()> {
+      _set_withdraw_limit(ctx, withdraw_limit, limit_window)
+    }
+
+    pub fn set_allowlist_enabled(ctx: Context<SetAllowlistEnabled>, allowlist_enabled: bool) -> Result<()> {
+      _set_allowlist_enabled(ctx, allowlist_enabled)
+    }
+
+    pub fn add_to_allowlist(ctx: Context<AddToAllowlist>, depositor: Pubkey) -> Result<()> {
+      _add_to_allowlist(ctx, depositor)
+    }
+
+    pub fn remove_from_allowlist(ctx: Context<RemoveFromAllowlist>) -> Result<()> {
+      _remove_from_allowlist(ctx)
+    }
+
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Option<Pubkey>) -> Result<()> {
+      _set_guardian(ctx, guardian)
+    }
+
+    pub fn guardian_lock(ctx: Context<GuardianLock>) -> Result<()> {
+      _guardian_lock(ctx)
+    }
+
+    pub fn set_reward_rate(ctx: Context<SetRewardRate>, reward_rate_bps: u16) -> Result<()> {
+      _set_reward_rate(ctx, reward_rate_bps)
+    }
+
+    pub fn fund_rewards(ctx: Context<FundRewards>, amount: u64) -> Result<()> {
+      _fund_rewards(ctx, amount)
+    }
+
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+      _claim_rewards(ctx)
+    }
+
+    pub fn migrate_vault(ctx: Context<MigrateVault>) -> Result<()> {
+      _migrate_vault(ctx)
+    }
+
+    pub fn create_stream(ctx: Context<CreateStream>, recipient: Pubkey, rate: u64, cap: u64) -> Result<()> {
+      _create_stream(ctx, recipient, rate, cap)
+    }
+
+    pub fn claim_stream(ctx: Context<ClaimStream>) -> Result<()> {
+      _claim_stream(ctx)
+    }
+
+    pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+      _cancel_stream(ctx)
+    }
 }
@@ -1,8 +1,322 @@
 use anchor_lang::prelude::*;
+use crate::errors::VaultError;
+
+pub const MAX_SIGNERS: usize = 10;
+pub const BPS_DENOMINATOR: u128 = 10_000;
+/// Current `Vault` layout version. Accounts created before versioning read as 0.
+pub const VAULT_VERSION: u8 = 1;
+/// Size of a vault created by the original program: discriminator,
+/// `vault_authority` and `locked`.
+pub const ORIGINAL_VAULT_SPACE: usize = 8 + 32 + 1;
+/// Spare bytes at the end of `Vault` that new fields can be carved out of
+/// without growing the account.
+pub const VAULT_RESERVED_SPACE: usize = 31;
 
 #[account]
 #[derive(InitSpace)]
 pub struct Vault {
     pub vault_authority: Pubkey,
-    pub locked: bool,
+    /// Key the vault PDA is derived from; stays fixed when the authority changes.
+    pub creator: Pubkey,
+    /// Distinguishes the vaults of one creator, part of the vault PDA seeds.
+    pub id: u64,
+    pub bump: u8,
+    pub pending_authority: Option<Pubkey>,
+    pub deposits_paused: bool,
+    pub withdrawals_paused: bool,
+    pub withdraw_delay: i64,
+    #[max_len(MAX_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_count: u64,
+    pub depositor_balance: u64,
+    /// Max lamports withdrawable per `limit_window` seconds, 0 disables the limit.
+    pub withdraw_limit: u64,
+    pub limit_window: i64,
+    pub window_start: i64,
+    pub window_withdrawn: u64,
+    pub allowlist_enabled: bool,
+    /// Optional key that can lock the vault in an emergency, but cannot unlock or withdraw.
+    pub guardian: Option<Pubkey>,
+    /// Rewards accrued per second on depositor balances, in basis points.
+    /// Change it through `update_reward_index` first, so elapsed time is priced at the old rate.
+    pub reward_rate_bps: u16,
+    /// Lamports set aside in the vault to pay depositor rewards.
+    pub reward_reserve: u64,
+    /// Cumulative lamports deposited into and withdrawn from the vault (token
+    /// transfers are not counted).
+    pub total_deposited: u64,
+    pub total_withdrawn: u64,
+    pub deposit_count: u64,
+    /// Slot of the last lamport deposit or withdrawal.
+    pub last_activity_slot: u64,
+    /// Layout version, see `VAULT_VERSION`. New fields go after it and shrink `reserved`
+    /// by their size, so older fields keep their offsets.
+    pub version: u8,
+    /// Sum of `reward_rate_bps * elapsed seconds` since rewards started, i.e. the
+    /// rewards per lamport of depositor balance scaled by `BPS_DENOMINATOR`.
+    pub reward_index: u128,
+    /// Timestamp `reward_index` was last advanced to, 0 until the first update.
+    pub reward_index_updated: i64,
+    /// Streams created from this vault that have not been cancelled yet.
+    pub open_streams: u64,
+    /// Set on vaults created by the original program, whose address is seeded with
+    /// `[b"vault", creator]` only, see `id_seed`.
+    pub legacy_address: bool,
+    pub reserved: [u8; VAULT_RESERVED_SPACE],
+}
+
+impl Vault {
+    /// A vault is locked when both deposits and withdrawals are paused.
+    pub fn is_locked(&self) -> bool {
+        self.deposits_paused && self.withdrawals_paused
+    }
+
+    /// Pauses or resumes both directions at once, used by toggle_lock.
+    pub fn set_locked(&mut self, locked: bool) {
+        self.deposits_paused = locked;
+        self.withdrawals_paused = locked;
+    }
+
+    /// The `id` seed of the vault PDA. Vaults with a legacy address were derived
+    /// without one, and an empty seed leaves the derivation unchanged.
+    pub fn id_seed(&self) -> Vec<u8> {
+        if self.legacy_address {
+            Vec::new()
+        } else {
+            self.id.to_le_bytes().to_vec()
+        }
+    }
+
+    pub fn is_multisig(&self) -> bool {
+        self.threshold > 0
+    }
+
+    /// Lamports held above the rent-exempt minimum of the vault account,
+    /// i.e. what can be paid out without the account being garbage-collected.
+    pub fn surplus_lamports(vault_info: &AccountInfo) -> Result<u64> {
+        let rent_exempt_reserve = Rent::get()?.minimum_balance(vault_info.data_len());
+
+        Ok(vault_info.lamports().saturating_sub(rent_exempt_reserve))
+    }
+
+    /// Surplus lamports not owed to depositors or set aside for rewards, i.e.
+    /// what the vault authority can pay out.
+    pub fn authority_lamports(&self, vault_info: &AccountInfo) -> Result<u64> {
+        let surplus = Self::surplus_lamports(vault_info)?;
+
+        Ok(surplus.saturating_sub(self.depositor_balance).saturating_sub(self.reward_reserve))
+    }
+
+    /// Counts `amount` against the current rate limit window, starting a new
+    /// window once the previous one has elapsed.
+    pub fn record_withdrawal(&mut self, amount: u64, now: i64) -> Result<()> {
+        if self.withdraw_limit == 0 {
+            return Ok(());
+        }
+
+        let window_end = self.window_start.checked_add(self.limit_window).ok_or(VaultError::Overflow)?;
+
+        if now >= window_end {
+            self.window_start = now;
+            self.window_withdrawn = 0;
+        }
+
+        let window_withdrawn = self.window_withdrawn.checked_add(amount).ok_or(VaultError::Overflow)?;
+
+        if window_withdrawn > self.withdraw_limit {
+            return Err(VaultError::WithdrawLimitExceeded.into());
+        }
+
+        self.window_withdrawn = window_withdrawn;
+
+        Ok(())
+    }
+
+    /// Advances the reward index to `now` at the current reward rate. Must run
+    /// before the rate changes and before a position checkpoints the index.
+    pub fn update_reward_index(&mut self, now: i64) -> Result<()> {
+        if self.reward_index_updated > 0 && now > self.reward_index_updated {
+            let elapsed = (now - self.reward_index_updated) as u128;
+            let accrued = (self.reward_rate_bps as u128).checked_mul(elapsed).ok_or(VaultError::Overflow)?;
+
+            self.reward_index = self.reward_index.checked_add(accrued).ok_or(VaultError::Overflow)?;
+        }
+
+        if now > self.reward_index_updated {
+            self.reward_index_updated = now;
+        }
+
+        Ok(())
+    }
+
+    /// Adds a lamport deposit to the vault statistics.
+    pub fn record_deposit(&mut self, amount: u64, slot: u64) -> Result<()> {
+        self.total_deposited = self.total_deposited.checked_add(amount).ok_or(VaultError::Overflow)?;
+        self.deposit_count = self.deposit_count.checked_add(1).ok_or(VaultError::Overflow)?;
+        self.last_activity_slot = slot;
+
+        Ok(())
+    }
+
+    /// Adds a lamport withdrawal to the vault statistics.
+    pub fn record_payout(&mut self, amount: u64, slot: u64) -> Result<()> {
+        self.total_withdrawn = self.total_withdrawn.checked_add(amount).ok_or(VaultError::Overflow)?;
+        self.last_activity_slot = slot;
+
+        Ok(())
+    }
+
+    /// Checks that a depositor may deposit. `allowlist_entry` must be the
+    /// depositor's allowlist PDA, it only counts once the program has created it.
+    pub fn check_allowlisted(&self, allowlist_entry: &AccountInfo) -> Result<()> {
+        if self.allowlist_enabled && (allowlist_entry.owner != &crate::ID || allowlist_entry.data_is_empty()) {
+            return Err(VaultError::NotAllowlisted.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+
+    /// Replaces the multisig signer set. An empty set with a zero threshold
+    /// switches the vault back to single authority mode.
+    pub fn set_signers(&mut self, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        if signers.len() > MAX_SIGNERS {
+            return Err(VaultError::TooManySigners.into());
+        }
+
+        if threshold as usize > signers.len() || (threshold == 0 && !signers.is_empty()) {
+            return Err(VaultError::InvalidThreshold.into());
+        }
+
+        for (i, signer) in signers.iter().enumerate() {
+            if signers[..i].contains(signer) {
+                return Err(VaultError::DuplicateSigner.into());
+            }
+        }
+
+        self.signers = signers;
+        self.threshold = threshold;
+
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DepositorPosition {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub balance: u64,
+    pub bump: u8,
+    pub pending_rewards: u64,
+    /// The vault's `reward_index` when this position was last accrued.
+    pub reward_index: u128,
+}
+
+impl DepositorPosition {
+    /// Adds the rewards earned by the current balance since the position last
+    /// checkpointed `reward_index`, which must be up to date. Must run before
+    /// every balance change so rewards are priced at the old balance.
+    pub fn accrue_rewards(&mut self, reward_index: u128) -> Result<()> {
+        let index_delta = reward_index.checked_sub(self.reward_index).ok_or(VaultError::Overflow)?;
+        let rewards = (self.balance as u128)
+            .checked_mul(index_delta)
+            .map(|value| value / BPS_DENOMINATOR)
+            .ok_or(VaultError::Overflow)?;
+        let rewards = u64::try_from(rewards).map_err(|_| VaultError::Overflow)?;
+
+        self.pending_rewards = self.pending_rewards.checked_add(rewards).ok_or(VaultError::Overflow)?;
+        self.reward_index = reward_index;
+
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistEntry {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PendingWithdrawal {
+    pub vault: Pubkey,
+    pub amount: u64,
+    pub unlock_timestamp: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ProposalAction {
+    Withdraw { amount: u64, recipient: Pubkey },
+    /// Pays `amount` tokens of `mint` from the vault's token account to the token account `recipient`.
+    WithdrawToken { mint: Pubkey, amount: u64, recipient: Pubkey },
+    ToggleLock,
+    SetPause { deposits_paused: bool, withdrawals_paused: bool },
+    ProposeAuthority { new_authority: Pubkey },
+    SetGuardian { guardian: Option<Pubkey> },
+    SetRewardRate { reward_rate_bps: u16 },
+    SetSigners {
+        #[max_len(MAX_SIGNERS)]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub vault: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    #[max_len(MAX_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// Lamports the vault authority has set aside for `recipient`, released at
+/// `rate` lamports per second up to `cap`.
+#[account]
+#[derive(InitSpace)]
+pub struct Stream {
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub rate: u64,
+    pub cap: u64,
+    pub claimed: u64,
+    pub last_claim: i64,
+    pub bump: u8,
+}
+
+impl Stream {
+    /// Lamports still held for the recipient, claimed or not.
+    pub fn remaining(&self) -> Result<u64> {
+        let remaining = self.cap.checked_sub(self.claimed).ok_or(VaultError::Overflow)?;
+
+        Ok(remaining)
+    }
+
+    /// Lamports accrued since the last claim, capped at what the stream still holds.
+    pub fn claimable(&self, now: i64) -> Result<u64> {
+        let elapsed = now.saturating_sub(self.last_claim).max(0) as u64;
+
+        Ok(self.rate.saturating_mul(elapsed).min(self.remaining()?))
+    }
+
+    /// Marks `amount` as paid out at `now`.
+    pub fn record_claim(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.claimed = self.claimed.checked_add(amount).ok_or(VaultError::Overflow)?;
+        self.last_claim = now;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,221 @@
+mod common;
+
+use anchor_spl::token::spl_token;
+use common::*;
+use on_chain_vault::errors::VaultError;
+use on_chain_vault_client::instructions;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+/// A vault owned by `authority` and a funded `mallory` who is not its authority.
+struct Fixture {
+    test: VaultTest,
+    authority: Keypair,
+    vault: Pubkey,
+    mallory: Keypair,
+}
+
+impl Fixture {
+    async fn new(withdraw_delay: i64) -> Self {
+        let mut test = VaultTest::new().await;
+        let (authority, vault) = test.vault_with_authority(false, withdraw_delay).await;
+        let mallory = test.funded_keypair();
+
+        Self { test, authority, vault, mallory }
+    }
+
+    /// Sends `ix` signed by mallory and expects the vault to reject it.
+    async fn assert_rejects_mallory(&mut self, ix: Instruction) {
+        let result = self.test.send(&[ix], &[&self.mallory]).await;
+
+        assert_vault_error(result, VaultError::Unauthorized);
+    }
+
+    async fn request_withdraw(&mut self) {
+        let ix = instructions::request_withdraw(&self.authority.pubkey(), &self.vault, 1);
+
+        self.test.send(&[ix], &[&self.authority]).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn withdraw() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::withdraw(&fixture.mallory.pubkey(), &fixture.vault, 1);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn withdraw_all() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::withdraw_all(&fixture.mallory.pubkey(), &fixture.vault);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn withdraw_to_many() {
+    let mut fixture = Fixture::new(0).await;
+    let mallory = fixture.mallory.pubkey();
+    let ix = instructions::withdraw_to_many(&mallory, &fixture.vault, &[(mallory, 1)]);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn toggle_lock() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::toggle_lock(&fixture.mallory.pubkey(), &fixture.vault);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn set_pause() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::set_pause(&fixture.mallory.pubkey(), &fixture.vault, true, true);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn withdraw_token() {
+    let mut fixture = Fixture::new(0).await;
+    let mint = fixture.test.create_mint();
+    fixture.test.create_token_account(&mint, &fixture.vault, 100);
+
+    let ix = instructions::withdraw_token(&fixture.mallory.pubkey(), &fixture.vault, &mint, &spl_token::ID, 100);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn request_withdraw() {
+    let mut fixture = Fixture::new(60).await;
+    let ix = instructions::request_withdraw(&fixture.mallory.pubkey(), &fixture.vault, 1);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn execute_withdraw() {
+    let mut fixture = Fixture::new(60).await;
+    fixture.request_withdraw().await;
+    fixture.test.advance_clock(60).await;
+
+    let ix = instructions::execute_withdraw(&fixture.mallory.pubkey(), &fixture.vault);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn cancel_withdraw() {
+    let mut fixture = Fixture::new(60).await;
+    fixture.request_withdraw().await;
+
+    let ix = instructions::cancel_withdraw(&fixture.mallory.pubkey(), &fixture.vault);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn set_multisig() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::set_multisig(&fixture.mallory.pubkey(), &fixture.vault, vec![fixture.mallory.pubkey()], 1);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn close_vault() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::close_vault(&fixture.mallory.pubkey(), &fixture.vault);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn propose_authority() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::propose_authority(&fixture.mallory.pubkey(), &fixture.vault, fixture.mallory.pubkey());
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn set_withdraw_limit() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::set_withdraw_limit(&fixture.mallory.pubkey(), &fixture.vault, 0, 0);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn set_allowlist_enabled() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::set_allowlist_enabled(&fixture.mallory.pubkey(), &fixture.vault, true);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn add_to_allowlist() {
+    let mut fixture = Fixture::new(0).await;
+    let depositor = fixture.mallory.pubkey();
+    let ix = instructions::add_to_allowlist(&fixture.mallory.pubkey(), &fixture.vault, depositor);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn remove_from_allowlist() {
+    let mut fixture = Fixture::new(0).await;
+    let depositor = Pubkey::new_unique();
+
+    let add = instructions::add_to_allowlist(&fixture.authority.pubkey(), &fixture.vault, depositor);
+    fixture.test.send(&[add], &[&fixture.authority]).await.unwrap();
+
+    let ix = instructions::remove_from_allowlist(&fixture.mallory.pubkey(), &fixture.vault, &depositor);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn set_guardian() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::set_guardian(&fixture.mallory.pubkey(), &fixture.vault, None);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn set_reward_rate() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::set_reward_rate(&fixture.mallory.pubkey(), &fixture.vault, 10_000);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn create_stream() {
+    let mut fixture = Fixture::new(0).await;
+    let ix = instructions::create_stream(&fixture.mallory.pubkey(), &fixture.vault, &fixture.mallory.pubkey(), 1, 1);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
+
+#[tokio::test]
+async fn cancel_stream() {
+    let mut fixture = Fixture::new(0).await;
+    let recipient = Pubkey::new_unique();
+
+    let create = instructions::create_stream(&fixture.authority.pubkey(), &fixture.vault, &recipient, 1, 1_000);
+    fixture.test.fund_vault(&fixture.vault, 1_000_000).await;
+    fixture.test.send(&[create], &[&fixture.authority]).await.unwrap();
+
+    let ix = instructions::cancel_stream(&fixture.mallory.pubkey(), &fixture.vault, &recipient);
+
+    fixture.assert_rejects_mallory(ix).await;
+}
@@ -0,0 +1,180 @@
+#![allow(dead_code)]
+
+use anchor_lang::{system_program, AccountDeserialize};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use anchor_spl::token::spl_token::solana_program::program_option::COption;
+use anchor_spl::token::spl_token::solana_program::program_pack::Pack;
+use on_chain_vault::errors::VaultError;
+use on_chain_vault::state::Vault;
+use on_chain_vault_client::instructions;
+use on_chain_vault_client::pda::vault_address;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::{AccountSharedData, ReadableAccount, WritableAccount};
+use solana_sdk::account_info::AccountInfo;
+use solana_sdk::clock::Clock;
+use solana_sdk::entrypoint::ProgramResult;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+// Anchor's entrypoint ties the account slice to the account lifetime, which the
+// native processor signature does not, so the accounts are leaked for the test run.
+fn process_instruction<'info>(program_id: &Pubkey, accounts: &[AccountInfo<'info>], data: &[u8]) -> ProgramResult {
+    let accounts: &'info [AccountInfo<'info>] = Box::leak(Box::new(accounts.to_vec()));
+    on_chain_vault::entry(program_id, accounts, data)
+}
+
+pub fn assert_vault_error(result: Result<(), BanksClientError>, expected: VaultError) {
+    match result.expect_err("transaction should fail").unwrap() {
+        TransactionError::InstructionError(_, error) => assert_eq!(error, InstructionError::Custom(expected.into())),
+        error => panic!("unexpected transaction error: {error:?}"),
+    }
+}
+
+pub struct VaultTest {
+    pub context: ProgramTestContext,
+}
+
+impl VaultTest {
+    pub async fn new() -> Self {
+        let mut program_test = ProgramTest::new("on_chain_vault", on_chain_vault::ID, processor!(process_instruction));
+        program_test.prefer_bpf(false);
+
+        Self {
+            context: program_test.start_with_context().await,
+        }
+    }
+
+    /// Creates a keypair backed by a system account holding 10 SOL.
+    pub fn funded_keypair(&mut self) -> Keypair {
+        let keypair = Keypair::new();
+        let account = AccountSharedData::new(10 * LAMPORTS_PER_SOL, 0, &system_program::ID);
+
+        self.context.set_account(&keypair.pubkey(), &account);
+
+        keypair
+    }
+
+    /// Writes a rent-exempt account owned by `owner` holding `data`.
+    pub fn set_program_account(&mut self, address: &Pubkey, owner: &Pubkey, data: &[u8]) {
+        let mut account = AccountSharedData::new(Rent::default().minimum_balance(data.len()), data.len(), owner);
+        account.set_data_from_slice(data);
+
+        self.context.set_account(address, &account);
+    }
+
+    /// Creates an initialized SPL token mint with 6 decimals.
+    pub fn create_mint(&mut self) -> Pubkey {
+        let mint = Pubkey::new_unique();
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+
+        spl_token::state::Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+
+        self.set_program_account(&mint, &spl_token::ID, &data);
+        mint
+    }
+
+    /// Creates the associated token account of `owner` for `mint`, holding `amount` tokens.
+    pub fn create_token_account(&mut self, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Pubkey {
+        let address = get_associated_token_address(owner, mint);
+        let mut data = vec![0; spl_token::state::Account::LEN];
+
+        spl_token::state::Account {
+            mint: *mint,
+            owner: *owner,
+            amount,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+
+        self.set_program_account(&address, &spl_token::ID, &data);
+        address
+    }
+
+    /// Creates a funded authority with an initialized vault (id 0) and returns both.
+    pub async fn vault_with_authority(&mut self, locked: bool, withdraw_delay: i64) -> (Keypair, Pubkey) {
+        let authority = self.funded_keypair();
+
+        self.send(&[instructions::init_vault(&authority.pubkey(), 0, locked, withdraw_delay)], &[&authority])
+            .await
+            .unwrap();
+
+        let vault = vault_address(&authority.pubkey(), 0).0;
+        (authority, vault)
+    }
+
+    /// Adds `amount` lamports to `vault` outside of any depositor position, so
+    /// the vault authority can withdraw them.
+    pub async fn fund_vault(&mut self, vault: &Pubkey, amount: u64) {
+        let mut account: AccountSharedData = self.context.banks_client.get_account(*vault).await.unwrap().unwrap().into();
+        account.set_lamports(account.lamports() + amount);
+
+        self.context.set_account(vault, &account);
+    }
+
+    pub async fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+        // A fresh blockhash keeps repeated identical instructions from being deduplicated
+        let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+
+        self.context.banks_client.process_transaction(transaction).await
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> Option<T> {
+        let account = self.context.banks_client.get_account(*address).await.unwrap()?;
+
+        Some(T::try_deserialize(&mut account.data.as_slice()).unwrap())
+    }
+
+    pub async fn vault(&mut self, address: &Pubkey) -> Vault {
+        self.account(address).await.expect("vault should exist")
+    }
+
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.context.banks_client.get_balance(*address).await.unwrap()
+    }
+
+    pub async fn rent_exempt_minimum(&mut self, address: &Pubkey) -> u64 {
+        let account = self.context.banks_client.get_account(*address).await.unwrap().unwrap();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+
+        rent.minimum_balance(account.data.len())
+    }
+
+    /// Token balance of an SPL token account.
+    pub async fn token_amount(&mut self, address: &Pubkey) -> u64 {
+        let account = self.context.banks_client.get_account(*address).await.unwrap().unwrap();
+
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    pub async fn clock(&mut self) -> Clock {
+        self.context.banks_client.get_sysvar::<Clock>().await.unwrap()
+    }
+
+    pub async fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.clock().await;
+        clock.unix_timestamp += seconds;
+        self.context.set_sysvar(&clock);
+    }
+}
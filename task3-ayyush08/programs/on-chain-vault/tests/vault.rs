@@ -0,0 +1,699 @@
+mod common;
+
+use anchor_lang::{AccountSerialize, Discriminator, Space};
+use anchor_spl::token::spl_token;
+use common::*;
+use on_chain_vault::errors::VaultError;
+use on_chain_vault::state::{
+    DepositorPosition, PendingWithdrawal, Proposal, ProposalAction, Stream, Vault, MAX_SIGNERS, ORIGINAL_VAULT_SPACE,
+    VAULT_RESERVED_SPACE, VAULT_VERSION,
+};
+use on_chain_vault_client::instructions;
+use on_chain_vault_client::pda::{
+    original_vault_address, pending_withdrawal_address, position_address, proposal_address, stream_address,
+    vault_address,
+};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::TransactionError;
+
+#[tokio::test]
+async fn init_vault() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    let state = test.vault(&vault).await;
+    assert_eq!(state.vault_authority, authority.pubkey());
+    assert_eq!(state.creator, authority.pubkey());
+    assert_eq!(state.id, 0);
+    assert_eq!(state.version, VAULT_VERSION);
+    assert!(!state.deposits_paused);
+    assert!(!state.withdrawals_paused);
+    assert_eq!(state.withdraw_delay, 0);
+    assert!(!state.is_multisig());
+}
+
+#[tokio::test]
+async fn authority_opens_several_vaults() {
+    let mut test = VaultTest::new().await;
+    let (authority, operations) = test.vault_with_authority(false, 0).await;
+    let user = test.funded_keypair();
+
+    test.send(&[instructions::init_vault(&authority.pubkey(), 1, true, 0)], &[&authority])
+        .await
+        .unwrap();
+
+    let payroll = vault_address(&authority.pubkey(), 1).0;
+    assert_ne!(payroll, operations);
+
+    let state = test.vault(&payroll).await;
+    assert_eq!(state.vault_authority, authority.pubkey());
+    assert_eq!(state.id, 1);
+    assert!(state.is_locked());
+
+    // The vaults are independent, locking one leaves the other open
+    test.send(&[instructions::deposit(&user.pubkey(), &operations, LAMPORTS_PER_SOL)], &[&user])
+        .await
+        .unwrap();
+    assert_eq!(test.vault(&operations).await.depositor_balance, LAMPORTS_PER_SOL);
+}
+
+#[tokio::test]
+async fn init_vault_locked() {
+    let mut test = VaultTest::new().await;
+    let (_, vault) = test.vault_with_authority(true, 0).await;
+
+    assert!(test.vault(&vault).await.is_locked());
+}
+
+#[tokio::test]
+async fn deposit_credits_vault_and_position() {
+    let mut test = VaultTest::new().await;
+    let (_, vault) = test.vault_with_authority(false, 0).await;
+    let user = test.funded_keypair();
+
+    let vault_before = test.lamports(&vault).await;
+
+    test.send(&[instructions::deposit(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user])
+        .await
+        .unwrap();
+    test.send(&[instructions::deposit(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user])
+        .await
+        .unwrap();
+
+    assert_eq!(test.lamports(&vault).await, vault_before + 2 * LAMPORTS_PER_SOL);
+    assert_eq!(test.vault(&vault).await.depositor_balance, 2 * LAMPORTS_PER_SOL);
+
+    let position: DepositorPosition = test.account(&position_address(&vault, &user.pubkey()).0).await.unwrap();
+    assert_eq!(position.vault, vault);
+    assert_eq!(position.user, user.pubkey());
+    assert_eq!(position.balance, 2 * LAMPORTS_PER_SOL);
+}
+
+#[tokio::test]
+async fn deposit_more_than_balance_fails_in_transfer() {
+    let mut test = VaultTest::new().await;
+    let (_, vault) = test.vault_with_authority(false, 0).await;
+    let user = test.funded_keypair();
+
+    let result = test.send(&[instructions::deposit(&user.pubkey(), &vault, 100 * LAMPORTS_PER_SOL)], &[&user]).await;
+
+    // SystemError::ResultWithNegativeLamports, raised by the system program transfer
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(_, error) => assert_eq!(error, InstructionError::Custom(1)),
+        error => panic!("unexpected transaction error: {error:?}"),
+    }
+}
+
+#[tokio::test]
+async fn withdraw_pays_authority() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+
+    let authority_before = test.lamports(&authority.pubkey()).await;
+    let vault_before = test.lamports(&vault).await;
+
+    test.send(&[instructions::withdraw(&authority.pubkey(), &vault, LAMPORTS_PER_SOL / 2)], &[&authority])
+        .await
+        .unwrap();
+
+    assert_eq!(test.lamports(&vault).await, vault_before - LAMPORTS_PER_SOL / 2);
+    assert_eq!(test.lamports(&authority.pubkey()).await, authority_before + LAMPORTS_PER_SOL / 2);
+}
+
+#[tokio::test]
+async fn deposit_and_withdraw_update_statistics() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let user = test.funded_keypair();
+
+    test.send(&[instructions::deposit(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user])
+        .await
+        .unwrap();
+    test.send(&[instructions::deposit(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user])
+        .await
+        .unwrap();
+    test.fund_vault(&vault, LAMPORTS_PER_SOL / 2).await;
+    test.send(&[instructions::withdraw(&authority.pubkey(), &vault, LAMPORTS_PER_SOL / 2)], &[&authority])
+        .await
+        .unwrap();
+
+    let slot = test.context.banks_client.get_root_slot().await.unwrap();
+    let state = test.vault(&vault).await;
+    assert_eq!(state.total_deposited, 2 * LAMPORTS_PER_SOL);
+    assert_eq!(state.deposit_count, 2);
+    assert_eq!(state.total_withdrawn, LAMPORTS_PER_SOL / 2);
+    assert!(state.last_activity_slot > 0 && state.last_activity_slot <= slot);
+}
+
+#[tokio::test]
+async fn withdraw_all_keeps_rent_and_deposits() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let user = test.funded_keypair();
+
+    test.send(&[instructions::deposit(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user])
+        .await
+        .unwrap();
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+    test.send(&[instructions::withdraw_all(&authority.pubkey(), &vault)], &[&authority]).await.unwrap();
+
+    let rent_exempt_minimum = test.rent_exempt_minimum(&vault).await;
+    assert_eq!(test.lamports(&vault).await, rent_exempt_minimum + LAMPORTS_PER_SOL);
+
+    // The depositor can still take their whole balance back
+    test.send(&[instructions::depositor_withdraw(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user])
+        .await
+        .unwrap();
+
+    assert_eq!(test.lamports(&vault).await, rent_exempt_minimum);
+    assert_eq!(test.vault(&vault).await.depositor_balance, 0);
+}
+
+#[tokio::test]
+async fn toggle_lock_flips_both_pauses() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    test.send(&[instructions::toggle_lock(&authority.pubkey(), &vault)], &[&authority])
+        .await
+        .unwrap();
+    assert!(test.vault(&vault).await.is_locked());
+
+    test.send(&[instructions::toggle_lock(&authority.pubkey(), &vault)], &[&authority])
+        .await
+        .unwrap();
+
+    let state = test.vault(&vault).await;
+    assert!(!state.deposits_paused);
+    assert!(!state.withdrawals_paused);
+}
+
+#[tokio::test]
+async fn depositor_withdraw_debits_position() {
+    let mut test = VaultTest::new().await;
+    let (_, vault) = test.vault_with_authority(false, 0).await;
+    let user = test.funded_keypair();
+
+    test.send(&[instructions::deposit(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user])
+        .await
+        .unwrap();
+    test.send(&[instructions::depositor_withdraw(&user.pubkey(), &vault, LAMPORTS_PER_SOL / 4)], &[&user])
+        .await
+        .unwrap();
+
+    let position: DepositorPosition = test.account(&position_address(&vault, &user.pubkey()).0).await.unwrap();
+    assert_eq!(position.balance, 3 * LAMPORTS_PER_SOL / 4);
+    assert_eq!(test.vault(&vault).await.depositor_balance, 3 * LAMPORTS_PER_SOL / 4);
+}
+
+#[tokio::test]
+async fn reward_rate_change_applies_from_then_on() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let user = test.funded_keypair();
+    let position = position_address(&vault, &user.pubkey()).0;
+
+    test.send(&[instructions::deposit(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user])
+        .await
+        .unwrap();
+
+    // A year at rate 0 earns nothing, even once the rate is raised
+    test.advance_clock(365 * 24 * 3600).await;
+    test.send(&[instructions::set_reward_rate(&authority.pubkey(), &vault, 1)], &[&authority]).await.unwrap();
+    test.advance_clock(10).await;
+
+    test.send(&[instructions::fund_rewards(&authority.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&authority])
+        .await
+        .unwrap();
+    test.send(&[instructions::claim_rewards(&user.pubkey(), &vault)], &[&user]).await.unwrap();
+
+    // 1 bps of the balance per second, for the 10 seconds since the change
+    let rewards = LAMPORTS_PER_SOL / 10_000 * 10;
+    assert_eq!(test.vault(&vault).await.reward_reserve, LAMPORTS_PER_SOL - rewards);
+    assert_eq!(test.account::<DepositorPosition>(&position).await.unwrap().pending_rewards, 0);
+}
+
+#[tokio::test]
+async fn timelocked_withdraw_after_delay() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 3600).await;
+
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+
+    let pending_withdrawal = pending_withdrawal_address(&vault).0;
+
+    test.send(&[instructions::request_withdraw(&authority.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&authority])
+        .await
+        .unwrap();
+
+    let request: PendingWithdrawal = test.account(&pending_withdrawal).await.unwrap();
+    assert_eq!(request.amount, LAMPORTS_PER_SOL);
+
+    test.advance_clock(3600).await;
+
+    let vault_before = test.lamports(&vault).await;
+
+    test.send(&[instructions::execute_withdraw(&authority.pubkey(), &vault)], &[&authority]).await.unwrap();
+
+    assert_eq!(test.lamports(&vault).await, vault_before - LAMPORTS_PER_SOL);
+    assert!(test.account::<PendingWithdrawal>(&pending_withdrawal).await.is_none());
+}
+
+#[tokio::test]
+async fn multisig_withdraw_proposal() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let alice = test.funded_keypair();
+    let bob = Keypair::new();
+    let recipient = Keypair::new().pubkey();
+
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+    test.send(
+        &[instructions::set_multisig(&authority.pubkey(), &vault, vec![alice.pubkey(), bob.pubkey()], 2)],
+        &[&authority],
+    )
+    .await
+    .unwrap();
+
+    let proposal = proposal_address(&vault, 0).0;
+
+    test.send(
+        &[instructions::create_proposal(
+            &alice.pubkey(),
+            &vault,
+            0,
+            ProposalAction::Withdraw { amount: LAMPORTS_PER_SOL / 2, recipient },
+        )],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    test.send(&[instructions::approve_proposal(&bob.pubkey(), &vault, 0)], &[&bob])
+        .await
+        .unwrap();
+
+    let state: Proposal = test.account(&proposal).await.unwrap();
+    assert_eq!(state.approvals, vec![alice.pubkey(), bob.pubkey()]);
+
+    test.send(&[instructions::execute_proposal(&bob.pubkey(), &vault, 0, &alice.pubkey(), Some(recipient))], &[&bob])
+        .await
+        .unwrap();
+
+    assert_eq!(test.lamports(&recipient).await, LAMPORTS_PER_SOL / 2);
+    assert!(test.account::<Proposal>(&proposal).await.is_none());
+}
+
+#[tokio::test]
+async fn approvals_of_rotated_out_signers_are_pruned() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let alice = test.funded_keypair();
+    let bob = Keypair::new();
+    let carol = Keypair::new();
+
+    test.send(
+        &[instructions::set_multisig(&authority.pubkey(), &vault, vec![alice.pubkey(), bob.pubkey()], 2)],
+        &[&authority],
+    )
+    .await
+    .unwrap();
+
+    // Proposal 0 is approved by alice and bob, then bob is rotated out for carol
+    test.send(&[instructions::create_proposal(&alice.pubkey(), &vault, 0, ProposalAction::ToggleLock)], &[&alice])
+        .await
+        .unwrap();
+    test.send(&[instructions::approve_proposal(&bob.pubkey(), &vault, 0)], &[&bob]).await.unwrap();
+    let set_signers = ProposalAction::SetSigners { signers: vec![alice.pubkey(), carol.pubkey()], threshold: 2 };
+    test.send(&[instructions::create_proposal(&alice.pubkey(), &vault, 1, set_signers)], &[&alice])
+        .await
+        .unwrap();
+    test.send(&[instructions::approve_proposal(&bob.pubkey(), &vault, 1)], &[&bob]).await.unwrap();
+    test.send(&[instructions::execute_proposal(&bob.pubkey(), &vault, 1, &alice.pubkey(), None)], &[&bob])
+        .await
+        .unwrap();
+
+    test.send(&[instructions::approve_proposal(&carol.pubkey(), &vault, 0)], &[&carol])
+        .await
+        .unwrap();
+
+    let state: Proposal = test.account(&proposal_address(&vault, 0).0).await.unwrap();
+    assert_eq!(state.approvals, vec![alice.pubkey(), carol.pubkey()]);
+}
+
+#[tokio::test]
+async fn multisig_withdraw_token_proposal() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let alice = test.funded_keypair();
+    let bob = Keypair::new();
+    let mint = test.create_mint();
+    let vault_token_account = test.create_token_account(&mint, &vault, 100);
+    let recipient = test.create_token_account(&mint, &Pubkey::new_unique(), 0);
+
+    test.send(
+        &[instructions::set_multisig(&authority.pubkey(), &vault, vec![alice.pubkey(), bob.pubkey()], 2)],
+        &[&authority],
+    )
+    .await
+    .unwrap();
+
+    let proposal = proposal_address(&vault, 0).0;
+
+    test.send(
+        &[instructions::create_proposal(
+            &alice.pubkey(),
+            &vault,
+            0,
+            ProposalAction::WithdrawToken { mint, amount: 40, recipient },
+        )],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    test.send(&[instructions::approve_proposal(&bob.pubkey(), &vault, 0)], &[&bob])
+        .await
+        .unwrap();
+
+    test.send(
+        &[instructions::execute_token_proposal(
+            &bob.pubkey(),
+            &vault,
+            0,
+            &alice.pubkey(),
+            &mint,
+            &recipient,
+            &spl_token::ID,
+        )],
+        &[&bob],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(test.token_amount(&vault_token_account).await, 60);
+    assert_eq!(test.token_amount(&recipient).await, 40);
+    assert!(test.account::<Proposal>(&proposal).await.is_none());
+}
+
+#[tokio::test]
+async fn close_vault_returns_lamports() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    let vault_lamports = test.lamports(&vault).await;
+    let authority_before = test.lamports(&authority.pubkey()).await;
+
+    test.send(&[instructions::close_vault(&authority.pubkey(), &vault)], &[&authority]).await.unwrap();
+
+    assert_eq!(test.lamports(&vault).await, 0);
+    assert_eq!(test.lamports(&authority.pubkey()).await, authority_before + vault_lamports);
+}
+
+#[tokio::test]
+async fn stream_pays_recipient_over_time() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let recipient = test.funded_keypair();
+    let stream = stream_address(&vault, &recipient.pubkey()).0;
+
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+    let vault_before = test.lamports(&vault).await;
+
+    test.send(
+        &[instructions::create_stream(&authority.pubkey(), &vault, &recipient.pubkey(), 1_000, 100_000)],
+        &[&authority],
+    )
+    .await
+    .unwrap();
+
+    let state: Stream = test.account(&stream).await.unwrap();
+    assert_eq!(state.recipient, recipient.pubkey());
+    assert_eq!((state.rate, state.cap, state.claimed), (1_000, 100_000, 0));
+    assert_eq!(test.lamports(&vault).await, vault_before - 100_000);
+    assert_eq!(test.vault(&vault).await.total_withdrawn, 0);
+    assert_eq!(test.vault(&vault).await.open_streams, 1);
+
+    test.advance_clock(30).await;
+    let recipient_before = test.lamports(&recipient.pubkey()).await;
+
+    test.send(&[instructions::claim_stream(&recipient.pubkey(), &vault)], &[&recipient])
+        .await
+        .unwrap();
+
+    assert_eq!(test.lamports(&recipient.pubkey()).await, recipient_before + 30_000);
+    assert_eq!(test.account::<Stream>(&stream).await.unwrap().claimed, 30_000);
+    assert_eq!(test.vault(&vault).await.total_withdrawn, 30_000);
+
+    // Cancelling pays what accrued since the claim and refunds the rest of the cap
+    test.advance_clock(20).await;
+    let authority_before = test.lamports(&authority.pubkey()).await;
+    let stream_rent = test.rent_exempt_minimum(&stream).await;
+
+    test.send(&[instructions::cancel_stream(&authority.pubkey(), &vault, &recipient.pubkey())], &[&authority])
+        .await
+        .unwrap();
+
+    assert!(test.account::<Stream>(&stream).await.is_none());
+    assert_eq!(test.vault(&vault).await.open_streams, 0);
+    assert_eq!(test.vault(&vault).await.total_withdrawn, 50_000);
+    assert_eq!(test.lamports(&recipient.pubkey()).await, recipient_before + 50_000);
+    assert_eq!(test.lamports(&vault).await, vault_before - 50_000);
+    assert_eq!(test.lamports(&authority.pubkey()).await, authority_before + stream_rent);
+}
+
+#[tokio::test]
+async fn stream_claims_stop_at_cap() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let recipient = test.funded_keypair();
+
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+    test.send(
+        &[instructions::create_stream(&authority.pubkey(), &vault, &recipient.pubkey(), 1_000, 10_000)],
+        &[&authority],
+    )
+    .await
+    .unwrap();
+
+    test.advance_clock(3_600).await;
+    let recipient_before = test.lamports(&recipient.pubkey()).await;
+
+    test.send(&[instructions::claim_stream(&recipient.pubkey(), &vault)], &[&recipient])
+        .await
+        .unwrap();
+    assert_eq!(test.lamports(&recipient.pubkey()).await, recipient_before + 10_000);
+
+    let result = test.send(&[instructions::claim_stream(&recipient.pubkey(), &vault)], &[&recipient]).await;
+    assert_vault_error(result, VaultError::InsufficientBalance);
+}
+
+#[tokio::test]
+async fn stream_payouts_are_not_held_back_by_withdraw_limit() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let recipient = test.funded_keypair();
+
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+    test.send(
+        &[instructions::set_withdraw_limit(&authority.pubkey(), &vault, 100_000, 60)],
+        &[&authority],
+    )
+    .await
+    .unwrap();
+
+    // The cap counts against the limit once, when the stream is created
+    let result = test
+        .send(
+            &[instructions::create_stream(&authority.pubkey(), &vault, &recipient.pubkey(), 1_000, 100_001)],
+            &[&authority],
+        )
+        .await;
+    assert_vault_error(result, VaultError::WithdrawLimitExceeded);
+
+    test.send(
+        &[instructions::create_stream(&authority.pubkey(), &vault, &recipient.pubkey(), 1_000, 100_000)],
+        &[&authority],
+    )
+    .await
+    .unwrap();
+    assert_eq!(test.vault(&vault).await.window_withdrawn, 100_000);
+
+    // Accrued payouts above a lowered limit are still paid in full
+    test.send(
+        &[instructions::set_withdraw_limit(&authority.pubkey(), &vault, 1_000, 60)],
+        &[&authority],
+    )
+    .await
+    .unwrap();
+    test.advance_clock(30).await;
+    let recipient_before = test.lamports(&recipient.pubkey()).await;
+
+    test.send(&[instructions::claim_stream(&recipient.pubkey(), &vault)], &[&recipient])
+        .await
+        .unwrap();
+    assert_eq!(test.lamports(&recipient.pubkey()).await, recipient_before + 30_000);
+
+    test.advance_clock(20).await;
+
+    test.send(&[instructions::cancel_stream(&authority.pubkey(), &vault, &recipient.pubkey())], &[&authority])
+        .await
+        .unwrap();
+    assert_eq!(test.lamports(&recipient.pubkey()).await, recipient_before + 50_000);
+    assert_eq!(test.vault(&vault).await.open_streams, 0);
+    assert_eq!(test.vault(&vault).await.window_withdrawn, 0);
+}
+
+#[tokio::test]
+async fn migrate_vault_grows_legacy_layout() {
+    let mut test = VaultTest::new().await;
+    let authority = test.funded_keypair();
+    let payer = test.funded_keypair();
+    let vault = vault_address(&authority.pubkey(), 0).0;
+    let mut legacy = set_legacy_vault(&mut test, &authority.pubkey());
+
+    let vault_before = test.lamports(&vault).await;
+    let payer_before = test.lamports(&payer.pubkey()).await;
+
+    test.send(&[instructions::migrate_vault(&payer.pubkey(), &vault)], &[&payer])
+        .await
+        .unwrap();
+
+    let account = test.context.banks_client.get_account(vault).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + Vault::INIT_SPACE);
+    assert_eq!(account.lamports, test.rent_exempt_minimum(&vault).await);
+
+    let rent_delta = account.lamports - vault_before;
+    assert!(rent_delta > 0);
+    assert_eq!(test.lamports(&payer.pubkey()).await, payer_before - rent_delta);
+
+    legacy.version = VAULT_VERSION;
+    let mut expected = Vec::new();
+    legacy.try_serialize(&mut expected).unwrap();
+    assert_eq!(account.data, expected);
+}
+
+#[tokio::test]
+async fn migrate_vault_payer_covers_rent_of_funded_vault() {
+    let mut test = VaultTest::new().await;
+    let authority = test.funded_keypair();
+    let payer = test.funded_keypair();
+    let vault = vault_address(&authority.pubkey(), 0).0;
+
+    set_legacy_vault(&mut test, &authority.pubkey());
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+
+    let legacy_len = test.context.banks_client.get_account(vault).await.unwrap().unwrap().data.len();
+    let rent = test.context.banks_client.get_rent().await.unwrap();
+    let rent_delta = rent.minimum_balance(8 + Vault::INIT_SPACE) - rent.minimum_balance(legacy_len);
+    let vault_before = test.lamports(&vault).await;
+    let payer_before = test.lamports(&payer.pubkey()).await;
+
+    test.send(&[instructions::migrate_vault(&payer.pubkey(), &vault)], &[&payer])
+        .await
+        .unwrap();
+
+    // The vault's surplus is unchanged, the payer covered the whole rent delta
+    assert_eq!(test.lamports(&vault).await, vault_before + rent_delta);
+    assert_eq!(test.lamports(&vault).await - test.rent_exempt_minimum(&vault).await, LAMPORTS_PER_SOL);
+    assert_eq!(test.lamports(&payer.pubkey()).await, payer_before - rent_delta);
+}
+
+#[tokio::test]
+async fn migrate_original_vault_keeps_its_address() {
+    let mut test = VaultTest::new().await;
+    let authority = test.funded_keypair();
+    let payer = test.funded_keypair();
+    let (vault, bump) = original_vault_address(&authority.pubkey());
+
+    // `{ vault_authority, locked: true }` as written by the original program
+    let mut data = Vault::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(authority.pubkey().as_ref());
+    data.push(1);
+    assert_eq!(data.len(), ORIGINAL_VAULT_SPACE);
+    test.set_program_account(&vault, &on_chain_vault::ID, &data);
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+
+    test.send(&[instructions::migrate_vault(&payer.pubkey(), &vault)], &[&payer])
+        .await
+        .unwrap();
+
+    let migrated = test.vault(&vault).await;
+    assert_eq!(migrated.vault_authority, authority.pubkey());
+    assert_eq!(migrated.creator, authority.pubkey());
+    assert_eq!(migrated.id, 0);
+    assert_eq!(migrated.bump, bump);
+    assert!(migrated.legacy_address);
+    assert!(migrated.is_locked());
+    assert_eq!(migrated.version, VAULT_VERSION);
+
+    test.send(&[instructions::toggle_lock(&authority.pubkey(), &vault)], &[&authority])
+        .await
+        .unwrap();
+
+    let authority_before = test.lamports(&authority.pubkey()).await;
+
+    test.send(&[instructions::withdraw(&authority.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&authority])
+        .await
+        .unwrap();
+
+    assert_eq!(test.lamports(&authority.pubkey()).await, authority_before + LAMPORTS_PER_SOL);
+    assert_eq!(test.lamports(&vault).await, test.rent_exempt_minimum(&vault).await);
+}
+
+/// Bytes the first versioned layout appended to `Vault`: the version byte and
+/// 64 bytes of padding, since carved into later fields and `reserved`.
+const VERSIONED_TAIL_LEN: usize = 1 + 64;
+
+/// Writes `test_legacy_vault` as a vault created before versioning, which lacks
+/// the versioned tail, and returns it.
+fn set_legacy_vault(test: &mut VaultTest, authority: &Pubkey) -> Vault {
+    let legacy = test_legacy_vault(authority);
+    let mut data = Vec::new();
+    legacy.try_serialize(&mut data).unwrap();
+    data.truncate(data.len() - VERSIONED_TAIL_LEN);
+    test.set_program_account(&vault_address(authority, 0).0, &on_chain_vault::ID, &data);
+
+    legacy
+}
+
+/// A vault with every optional and variable-length field filled in.
+fn test_legacy_vault(authority: &Pubkey) -> Vault {
+    Vault {
+        vault_authority: *authority,
+        creator: *authority,
+        id: 0,
+        bump: vault_address(authority, 0).1,
+        pending_authority: Some(Pubkey::new_unique()),
+        deposits_paused: true,
+        withdrawals_paused: false,
+        withdraw_delay: 60,
+        signers: (0..MAX_SIGNERS)
+            .map(|_| Pubkey::new_unique())
+            .collect(),
+        threshold: 2,
+        proposal_count: 3,
+        depositor_balance: LAMPORTS_PER_SOL,
+        withdraw_limit: 5,
+        limit_window: 6,
+        window_start: 7,
+        window_withdrawn: 8,
+        allowlist_enabled: true,
+        guardian: Some(Pubkey::new_unique()),
+        reward_rate_bps: 100,
+        reward_reserve: 9,
+        total_deposited: 10,
+        total_withdrawn: 11,
+        deposit_count: 12,
+        last_activity_slot: 13,
+        version: 0,
+        reward_index: 0,
+        reward_index_updated: 0,
+        open_streams: 0,
+        legacy_address: false,
+        reserved: [0; VAULT_RESERVED_SPACE],
+    }
+}
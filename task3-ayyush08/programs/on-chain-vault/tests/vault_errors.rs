@@ -0,0 +1,438 @@
+mod common;
+
+use anchor_spl::token::spl_token;
+use common::*;
+use on_chain_vault::errors::VaultError;
+use on_chain_vault::state::{ProposalAction, MAX_SIGNERS};
+use on_chain_vault_client::instructions;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+/// Creates a vault in multisig mode with two signers (threshold 2) and returns
+/// the signers alongside the vault address.
+async fn multisig_vault(test: &mut VaultTest) -> (Keypair, Keypair, Pubkey) {
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let alice = test.funded_keypair();
+    let bob = test.funded_keypair();
+
+    test.send(
+        &[instructions::set_multisig(&authority.pubkey(), &vault, vec![alice.pubkey(), bob.pubkey()], 2)],
+        &[&authority],
+    )
+    .await
+    .unwrap();
+
+    (alice, bob, vault)
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    let mut test = VaultTest::new().await;
+    let (_, vault) = test.vault_with_authority(false, 0).await;
+    let mallory = test.funded_keypair();
+
+    let result = test.send(&[instructions::toggle_lock(&mallory.pubkey(), &vault)], &[&mallory]).await;
+
+    assert_vault_error(result, VaultError::Unauthorized);
+    assert!(!test.vault(&vault).await.is_locked());
+}
+
+#[tokio::test]
+async fn vault_locked() {
+    let mut test = VaultTest::new().await;
+    let (_, vault) = test.vault_with_authority(true, 0).await;
+    let user = test.funded_keypair();
+
+    let result = test.send(&[instructions::deposit(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user]).await;
+
+    assert_vault_error(result, VaultError::VaultLocked);
+}
+
+#[tokio::test]
+async fn overflow() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let payouts = [(Pubkey::new_unique(), u64::MAX), (Pubkey::new_unique(), 1)];
+
+    let result = test
+        .send(&[instructions::withdraw_to_many(&authority.pubkey(), &vault, &payouts)], &[&authority])
+        .await;
+
+    assert_vault_error(result, VaultError::Overflow);
+}
+
+#[tokio::test]
+async fn insufficient_balance() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    let result = test
+        .send(&[instructions::withdraw(&authority.pubkey(), &vault, 100 * LAMPORTS_PER_SOL)], &[&authority])
+        .await;
+
+    assert_vault_error(result, VaultError::InsufficientBalance);
+}
+
+#[tokio::test]
+async fn below_rent_exempt_minimum() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    let result = test.send(&[instructions::withdraw(&authority.pubkey(), &vault, 1)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::BelowRentExemptMinimum);
+}
+
+#[tokio::test]
+async fn invalid_withdraw_delay() {
+    let mut test = VaultTest::new().await;
+    let authority = test.funded_keypair();
+
+    let result = test.send(&[instructions::init_vault(&authority.pubkey(), 0, false, -1)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::InvalidWithdrawDelay);
+}
+
+#[tokio::test]
+async fn withdraw_timelocked() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 60).await;
+
+    let result = test.send(&[instructions::withdraw(&authority.pubkey(), &vault, 1)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::WithdrawTimelocked);
+}
+
+#[tokio::test]
+async fn close_vault_timelocked() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 60).await;
+
+    let result = test.send(&[instructions::close_vault(&authority.pubkey(), &vault)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::WithdrawTimelocked);
+}
+
+#[tokio::test]
+async fn withdraw_token_timelocked() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 60).await;
+    let mint = test.create_mint();
+    test.create_token_account(&mint, &vault, 100);
+
+    let result = test
+        .send(&[instructions::withdraw_token(&authority.pubkey(), &vault, &mint, &spl_token::ID, 100)], &[&authority])
+        .await;
+
+    assert_vault_error(result, VaultError::WithdrawTimelocked);
+}
+
+#[tokio::test]
+async fn withdraw_not_ready() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 3600).await;
+
+    test.send(&[instructions::request_withdraw(&authority.pubkey(), &vault, 1)], &[&authority]).await.unwrap();
+
+    let result = test.send(&[instructions::execute_withdraw(&authority.pubkey(), &vault)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::WithdrawNotReady);
+}
+
+#[tokio::test]
+async fn multisig_required() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    test.send(&[instructions::set_multisig(&authority.pubkey(), &vault, vec![authority.pubkey()], 1)], &[&authority])
+        .await
+        .unwrap();
+
+    let result = test.send(&[instructions::withdraw(&authority.pubkey(), &vault, 1)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::MultisigRequired);
+}
+
+#[tokio::test]
+async fn not_multisig() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    let result = test
+        .send(
+            &[instructions::create_proposal(&authority.pubkey(), &vault, 0, ProposalAction::ToggleLock)],
+            &[&authority],
+        )
+        .await;
+
+    assert_vault_error(result, VaultError::NotMultisig);
+}
+
+#[tokio::test]
+async fn not_a_signer() {
+    let mut test = VaultTest::new().await;
+    let (_, _, vault) = multisig_vault(&mut test).await;
+    let mallory = test.funded_keypair();
+
+    let result = test
+        .send(&[instructions::create_proposal(&mallory.pubkey(), &vault, 0, ProposalAction::ToggleLock)], &[&mallory])
+        .await;
+
+    assert_vault_error(result, VaultError::NotASigner);
+}
+
+#[tokio::test]
+async fn already_approved() {
+    let mut test = VaultTest::new().await;
+    let (alice, _, vault) = multisig_vault(&mut test).await;
+
+    test.send(&[instructions::create_proposal(&alice.pubkey(), &vault, 0, ProposalAction::ToggleLock)], &[&alice])
+        .await
+        .unwrap();
+
+    let result = test.send(&[instructions::approve_proposal(&alice.pubkey(), &vault, 0)], &[&alice]).await;
+
+    assert_vault_error(result, VaultError::AlreadyApproved);
+}
+
+#[tokio::test]
+async fn insufficient_approvals() {
+    let mut test = VaultTest::new().await;
+    let (alice, _, vault) = multisig_vault(&mut test).await;
+
+    test.send(&[instructions::create_proposal(&alice.pubkey(), &vault, 0, ProposalAction::ToggleLock)], &[&alice])
+        .await
+        .unwrap();
+
+    let result = test
+        .send(&[instructions::execute_proposal(&alice.pubkey(), &vault, 0, &alice.pubkey(), None)], &[&alice])
+        .await;
+
+    assert_vault_error(result, VaultError::InsufficientApprovals);
+}
+
+#[tokio::test]
+async fn invalid_threshold() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let signers = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let result = test.send(&[instructions::set_multisig(&authority.pubkey(), &vault, signers, 3)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::InvalidThreshold);
+}
+
+#[tokio::test]
+async fn too_many_signers() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let signers = (0..=MAX_SIGNERS).map(|_| Pubkey::new_unique()).collect();
+
+    let result = test.send(&[instructions::set_multisig(&authority.pubkey(), &vault, signers, 1)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::TooManySigners);
+}
+
+#[tokio::test]
+async fn duplicate_signer() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let signer = Pubkey::new_unique();
+
+    let result = test
+        .send(&[instructions::set_multisig(&authority.pubkey(), &vault, vec![signer, signer], 1)], &[&authority])
+        .await;
+
+    assert_vault_error(result, VaultError::DuplicateSigner);
+}
+
+#[tokio::test]
+async fn invalid_recipient() {
+    let mut test = VaultTest::new().await;
+    let (alice, bob, vault) = multisig_vault(&mut test).await;
+    let action = ProposalAction::Withdraw { amount: 1, recipient: Pubkey::new_unique() };
+
+    test.send(&[instructions::create_proposal(&alice.pubkey(), &vault, 0, action)], &[&alice])
+        .await
+        .unwrap();
+    test.send(&[instructions::approve_proposal(&bob.pubkey(), &vault, 0)], &[&bob]).await.unwrap();
+
+    let result = test
+        .send(&[instructions::execute_proposal(&bob.pubkey(), &vault, 0, &alice.pubkey(), Some(bob.pubkey()))], &[&bob])
+        .await;
+
+    assert_vault_error(result, VaultError::InvalidRecipient);
+}
+
+#[tokio::test]
+async fn outstanding_deposits() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let user = test.funded_keypair();
+
+    test.send(&[instructions::deposit(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user])
+        .await
+        .unwrap();
+
+    let result = test.send(&[instructions::close_vault(&authority.pubkey(), &vault)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::OutstandingDeposits);
+}
+
+#[tokio::test]
+async fn not_pending_authority() {
+    let mut test = VaultTest::new().await;
+    let (_, vault) = test.vault_with_authority(false, 0).await;
+    let mallory = test.funded_keypair();
+
+    let result = test.send(&[instructions::accept_authority(&mallory.pubkey(), &vault)], &[&mallory]).await;
+
+    assert_vault_error(result, VaultError::NotPendingAuthority);
+}
+
+#[tokio::test]
+async fn withdraw_limit_exceeded() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+    test.send(&[instructions::set_withdraw_limit(&authority.pubkey(), &vault, 100, 60)], &[&authority]).await.unwrap();
+
+    let result = test.send(&[instructions::withdraw(&authority.pubkey(), &vault, 101)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::WithdrawLimitExceeded);
+}
+
+#[tokio::test]
+async fn close_vault_withdraw_limit_exceeded() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+    test.send(&[instructions::set_withdraw_limit(&authority.pubkey(), &vault, 100, 60)], &[&authority]).await.unwrap();
+
+    let result = test.send(&[instructions::close_vault(&authority.pubkey(), &vault)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::WithdrawLimitExceeded);
+}
+
+#[tokio::test]
+async fn invalid_limit_window() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    let result = test
+        .send(&[instructions::set_withdraw_limit(&authority.pubkey(), &vault, 100, 0)], &[&authority])
+        .await;
+
+    assert_vault_error(result, VaultError::InvalidLimitWindow);
+}
+
+#[tokio::test]
+async fn not_allowlisted() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let user = test.funded_keypair();
+
+    test.send(&[instructions::set_allowlist_enabled(&authority.pubkey(), &vault, true)], &[&authority]).await.unwrap();
+
+    let result = test.send(&[instructions::deposit(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user]).await;
+
+    assert_vault_error(result, VaultError::NotAllowlisted);
+}
+
+#[tokio::test]
+async fn not_guardian() {
+    let mut test = VaultTest::new().await;
+    let (_, vault) = test.vault_with_authority(false, 0).await;
+    let mallory = test.funded_keypair();
+
+    let result = test.send(&[instructions::guardian_lock(&mallory.pubkey(), &vault)], &[&mallory]).await;
+
+    assert_vault_error(result, VaultError::NotGuardian);
+}
+
+#[tokio::test]
+async fn recipient_mismatch() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let payouts = [(Pubkey::new_unique(), 1), (Pubkey::new_unique(), 1)];
+
+    // Two amounts but only one recipient account
+    let mut withdraw_to_many = instructions::withdraw_to_many(&authority.pubkey(), &vault, &payouts);
+    withdraw_to_many.accounts.pop();
+
+    let result = test.send(&[withdraw_to_many], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::RecipientMismatch);
+}
+
+#[tokio::test]
+async fn already_migrated() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    let result = test.send(&[instructions::migrate_vault(&authority.pubkey(), &vault)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::AlreadyMigrated);
+}
+
+#[tokio::test]
+async fn invalid_stream() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    let result = test
+        .send(&[instructions::create_stream(&authority.pubkey(), &vault, &Pubkey::new_unique(), 0, 1)], &[&authority])
+        .await;
+
+    assert_vault_error(result, VaultError::InvalidStream);
+}
+
+#[tokio::test]
+async fn reserved_funds() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+    let user = test.funded_keypair();
+
+    test.send(&[instructions::deposit(&user.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&user])
+        .await
+        .unwrap();
+
+    let result = test.send(&[instructions::withdraw(&authority.pubkey(), &vault, 1)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::ReservedFunds);
+}
+
+#[tokio::test]
+async fn open_streams() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    test.fund_vault(&vault, LAMPORTS_PER_SOL).await;
+    test.send(
+        &[instructions::create_stream(&authority.pubkey(), &vault, &Pubkey::new_unique(), 1, 1_000)],
+        &[&authority],
+    )
+    .await
+    .unwrap();
+
+    let result = test.send(&[instructions::close_vault(&authority.pubkey(), &vault)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::OpenStreams);
+}
+
+#[tokio::test]
+async fn close_vault_reward_reserve() {
+    let mut test = VaultTest::new().await;
+    let (authority, vault) = test.vault_with_authority(false, 0).await;
+
+    test.send(&[instructions::fund_rewards(&authority.pubkey(), &vault, LAMPORTS_PER_SOL)], &[&authority])
+        .await
+        .unwrap();
+
+    let result = test.send(&[instructions::close_vault(&authority.pubkey(), &vault)], &[&authority]).await;
+
+    assert_vault_error(result, VaultError::ReservedFunds);
+}
@@ -18,4 +18,20 @@ pub enum TwitterError {
     CommentTooLong,
     #[msg("Invalid Reaction Author")]
     InvalidReactionAuthor,
+    #[msg("Invalid Tweet Author")]
+    InvalidTweetAuthor,
+    #[msg("Revision does not belong to this tweet")]
+    InvalidRevision,
+    #[msg("Parent tweet still exists")]
+    ParentTweetExists,
+    #[msg("Parent tweet was deleted")]
+    ParentTweetDeleted,
+    #[msg("Maximum number of Reactions Reached")]
+    MaxReactionsReached,
+    #[msg("Minimum number of Reactions Reached")]
+    MinReactionsReached,
+    #[msg("Reply nested too deep")]
+    ReplyTooDeep,
+    #[msg("Invalid Parent Comment")]
+    InvalidParentComment,
 }
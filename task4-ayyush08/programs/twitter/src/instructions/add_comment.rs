@@ -5,8 +5,9 @@
 /// Requirements:
 /// - Validate that comment content doesn't exceed maximum length
 /// - Initialize a new comment account with proper PDA seeds
-/// - Set comment fields: content, author, parent tweet, and bump
-/// - Use content hash in PDA seeds for unique comment identification
+/// - Set comment fields: content, author, parent tweet and its generation, thread fields, and bump
+/// - Use content hash in PDA seeds for unique comment identification, together with
+///   the tweet's generation so comments on a deleted tweet don't collide with new ones
 /// 
 ///-------------------------------------------------------------------------------
 
@@ -24,8 +25,12 @@ pub fn add_comment(ctx: Context<AddCommentContext>, comment_content: String) ->
 
     comment.comment_author = ctx.accounts.comment_author.key();
     comment.parent_tweet = ctx.accounts.tweet.key();
+    comment.tweet_generation = ctx.accounts.tweet.generation;
     comment.content = comment_content;
-    
+    comment.parent_comment = None;
+    comment.depth = 0;
+    comment.reply_count = 0;
+
     comment.bump = ctx.bumps.comment;
 
     Ok(())
@@ -47,7 +52,8 @@ pub struct AddCommentContext<'info> {
             COMMENT_SEED.as_bytes(),
             comment_author.key().as_ref(),
             {&hash(comment_content.as_bytes()).to_bytes()},
-            tweet.key().as_ref()
+            tweet.key().as_ref(),
+            tweet.generation.to_le_bytes().as_ref()
         ],
         bump
     )]
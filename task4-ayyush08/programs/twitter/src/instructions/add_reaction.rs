@@ -1,14 +1,13 @@
 //-------------------------------------------------------------------------------
-use crate::errors::TwitterError;
 use crate::states::*;
 ///
 /// TASK: Implement the add reaction functionality for the Twitter program
 ///
 /// Requirements:
 /// - Initialize a new reaction account with proper PDA seeds
-/// - Increment the appropriate counter (likes or dislikes) on the tweet
-/// - Set reaction fields: type, author, parent tweet, and bump
-/// - Handle both Like and Dislike reaction types
+/// - Increment the tweet's counter for the reaction type
+/// - Set reaction fields: type, author, parent tweet, tweet generation and bump
+/// - Handle every reaction type
 ///
 ///-------------------------------------------------------------------------------
 use anchor_lang::prelude::*;
@@ -20,25 +19,13 @@ pub fn add_reaction(ctx: Context<AddReactionContext>, reaction: ReactionType) ->
     let tweet_reaction = &mut ctx.accounts.tweet_reaction;
     let author = &ctx.accounts.reaction_author;
 
-    match reaction {
-        ReactionType::Like => {
-            if tweet.likes == u64::MAX {
-                return Err(TwitterError::MaxLikesReached.into());
-            }
-            tweet.likes += 1;
-        }
-        ReactionType::Dislike => {
-            if tweet.dislikes == u64::MAX {
-                return Err(TwitterError::MaxDislikesReached.into());
-            }
-            tweet.dislikes += 1;
-        }
-    }
+    tweet.increment_reaction(reaction)?;
 
     tweet_reaction.reaction = reaction;
     tweet_reaction.reaction_author = author.key();
     tweet_reaction.parent_tweet = tweet.key();
     tweet_reaction.bump = ctx.bumps.tweet_reaction;
+    tweet_reaction.tweet_generation = tweet.generation;
 
     Ok(())
 }
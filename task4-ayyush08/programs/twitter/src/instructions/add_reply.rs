@@ -0,0 +1,80 @@
+//-------------------------------------------------------------------------------
+///
+/// Add reply functionality for the Twitter program
+///
+/// Requirements:
+/// - Validate that reply content doesn't exceed maximum length
+/// - Validate that the reply stays within the maximum reply depth
+/// - Validate that the parent comment belongs to the current generation of its tweet
+/// - Initialize a new comment account seeded by the parent comment instead of
+///   the tweet, with the content hash for unique identification
+/// - Set comment fields: content, author, parent tweet and comment, the tweet's
+///   generation, depth, and bump
+/// - Increment the reply counter on the parent comment
+///
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn add_reply(ctx: Context<AddReplyContext>, reply_content: String) -> Result<()> {
+    if reply_content.len() > COMMENT_LENGTH {
+        return Err(TwitterError::CommentTooLong.into());
+    }
+
+    let parent_comment = &mut ctx.accounts.parent_comment;
+
+    if !parent_comment.belongs_to(&ctx.accounts.tweet) {
+        return Err(TwitterError::ParentTweetDeleted.into());
+    }
+
+    if parent_comment.depth >= MAX_REPLY_DEPTH {
+        return Err(TwitterError::ReplyTooDeep.into());
+    }
+
+    let reply = &mut ctx.accounts.reply;
+
+    reply.comment_author = ctx.accounts.comment_author.key();
+    reply.parent_tweet = parent_comment.parent_tweet;
+    reply.content = reply_content;
+    reply.parent_comment = Some(parent_comment.key());
+    reply.tweet_generation = parent_comment.tweet_generation;
+    reply.depth = parent_comment.depth + 1;
+    reply.reply_count = 0;
+
+    reply.bump = ctx.bumps.reply;
+
+    parent_comment.reply_count += 1;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reply_content: String)]
+pub struct AddReplyContext<'info> {
+    #[account(mut)]
+    pub comment_author: Signer<'info>,
+
+    #[account(
+        init,
+        payer = comment_author,
+        space = 8 + Comment::INIT_SPACE,
+        seeds = [
+            COMMENT_SEED.as_bytes(),
+            comment_author.key().as_ref(),
+            {&hash(reply_content.as_bytes()).to_bytes()},
+            parent_comment.key().as_ref()
+        ],
+        bump
+    )]
+    pub reply: Account<'info, Comment>,
+
+    #[account(mut)]
+    pub parent_comment: Account<'info, Comment>,
+
+    #[account(address = parent_comment.parent_tweet @ TwitterError::InvalidParentComment)]
+    pub tweet: Account<'info, Tweet>,
+    pub system_program: Program<'info, System>,
+}
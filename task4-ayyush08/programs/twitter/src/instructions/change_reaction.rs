@@ -0,0 +1,64 @@
+//-------------------------------------------------------------------------------
+///
+/// Change reaction functionality for the Twitter program
+///
+/// Requirements:
+/// - Verify that the tweet reaction exists and belongs to the reaction author
+/// - Verify that the reaction was not made on a deleted tweet with the same address
+/// - Move the reaction from the old counter to the new one in a single step,
+///   keeping the reaction account and its rent
+/// - Changing to the current reaction type changes nothing
+///
+///-------------------------------------------------------------------------------
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn change_reaction(ctx: Context<ChangeReactionContext>, reaction: ReactionType) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    let reaction_author = &ctx.accounts.reaction_author;
+    let tweet_reaction = &mut ctx.accounts.tweet_reaction;
+
+    if reaction_author.key() != tweet_reaction.reaction_author {
+        return Err(TwitterError::InvalidReactionAuthor.into());
+    }
+
+    if tweet_reaction.parent_tweet != tweet.key() {
+        return Err(TwitterError::InvalidReactionAuthor.into());
+    }
+
+    if !tweet_reaction.belongs_to(tweet) {
+        return Err(TwitterError::ParentTweetDeleted.into());
+    }
+
+    if tweet_reaction.reaction == reaction {
+        return Ok(());
+    }
+
+    tweet.decrement_reaction(tweet_reaction.reaction)?;
+    tweet.increment_reaction(reaction)?;
+
+    tweet_reaction.reaction = reaction;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ChangeReactionContext<'info> {
+    pub reaction_author: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            TWEET_REACTION_SEED.as_bytes(),
+            reaction_author.key().as_ref(),
+            tweet.key().as_ref(),
+        ],
+        bump = tweet_reaction.bump
+    )]
+    pub tweet_reaction: Account<'info, Reaction>,
+
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+}
@@ -0,0 +1,72 @@
+//-------------------------------------------------------------------------------
+///
+/// Edit tweet functionality for the Twitter program
+///
+/// Requirements:
+/// - Only the tweet author can edit the tweet
+/// - Validate that the new content doesn't exceed maximum length
+/// - Store the current content in a revision account seeded by the tweet's
+///   generation and the edit number
+/// - Replace the content, bump the edit counter and set the last edited timestamp
+///
+///-------------------------------------------------------------------------------
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn edit_tweet(ctx: Context<EditTweetContext>, content: String) -> Result<()> {
+    if content.len() > CONTENT_LENGTH {
+        return Err(TwitterError::ContentTooLong.into());
+    }
+
+    let tweet = &mut ctx.accounts.tweet;
+    let tweet_revision = &mut ctx.accounts.tweet_revision;
+    let now = Clock::get()?.unix_timestamp;
+
+    tweet_revision.tweet_author = tweet.tweet_author;
+    tweet_revision.parent_tweet = tweet.key();
+    tweet_revision.tweet_generation = tweet.generation;
+    tweet_revision.revision = tweet.edit_count;
+    tweet_revision.content = std::mem::replace(&mut tweet.content, content);
+    tweet_revision.replaced_at = now;
+    tweet_revision.bump = ctx.bumps.tweet_revision;
+
+    tweet.edit_count += 1;
+    tweet.last_edited = now;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EditTweetContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = tweet_author @ TwitterError::InvalidTweetAuthor,
+        seeds = [
+            tweet.topic.as_bytes(),
+            TWEET_SEED.as_bytes(),
+            tweet_author.key().as_ref()
+        ],
+        bump = tweet.bump
+    )]
+    pub tweet: Account<'info, Tweet>,
+
+    #[account(
+        init,
+        payer = tweet_author,
+        space = 8 + TweetRevision::INIT_SPACE,
+        seeds = [
+            TWEET_REVISION_SEED.as_bytes(),
+            tweet.key().as_ref(),
+            tweet.generation.to_le_bytes().as_ref(),
+            tweet.edit_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub tweet_revision: Account<'info, TweetRevision>,
+    pub system_program: Program<'info, System>,
+}
@@ -5,8 +5,9 @@
 /// Requirements:
 /// - Validate that topic and content don't exceed maximum lengths
 /// - Initialize a new tweet account with proper PDA seeds
-/// - Set tweet fields: topic, content, author, likes, dislikes, and bump
-/// - Initialize counters (likes and dislikes) to zero
+/// - Set tweet fields: topic, content, author, reaction counters, generation and bump
+/// - Take the generation from the author's tweet counter and bump the counter
+/// - Initialize counters (reactions and edits) to zero
 /// - Use topic in PDA seeds for tweet identification
 ///
 ///-------------------------------------------------------------------------------
@@ -28,12 +29,19 @@ pub fn initialize_tweet(
     }
 
     let tweet = &mut ctx.accounts.tweet;
+    let tweet_counter = &mut ctx.accounts.tweet_counter;
 
     tweet.tweet_author = ctx.accounts.tweet_authority.key();
     tweet.topic = topic;
     tweet.content = content;
-    tweet.likes = 0;
-    tweet.dislikes = 0;
+    tweet.reactions = [0; REACTION_TYPES];
+    tweet.edit_count = 0;
+    tweet.last_edited = 0;
+    tweet.generation = tweet_counter.count;
+
+    tweet_counter.tweet_author = ctx.accounts.tweet_authority.key();
+    tweet_counter.count += 1;
+    tweet_counter.bump = ctx.bumps.tweet_counter;
 
     tweet.bump = ctx.bumps.tweet;
 
@@ -59,5 +67,17 @@ pub struct InitializeTweet<'info> {
         bump
     )]
     pub tweet: Account<'info, Tweet>,
+
+    #[account(
+        init_if_needed,
+        payer = tweet_authority,
+        space = 8 + TweetCounter::INIT_SPACE,
+        seeds = [
+            TWEET_COUNTER_SEED.as_bytes(),
+            tweet_authority.key().as_ref()
+        ],
+        bump
+    )]
+    pub tweet_counter: Account<'info, TweetCounter>,
     pub system_program: Program<'info, System>,
 }
@@ -12,3 +12,21 @@ pub mod add_comment;
 
 pub use remove_comment::*;
 pub mod remove_comment;
+
+pub use edit_tweet::*;
+pub mod edit_tweet;
+
+pub use remove_tweet::*;
+pub mod remove_tweet;
+
+pub use reclaim_reaction::*;
+pub mod reclaim_reaction;
+
+pub use reclaim_revision::*;
+pub mod reclaim_revision;
+
+pub use change_reaction::*;
+pub mod change_reaction;
+
+pub use add_reply::*;
+pub mod add_reply;
@@ -0,0 +1,54 @@
+//-------------------------------------------------------------------------------
+///
+/// Reclaim reaction functionality for the Twitter program
+///
+/// Requirements:
+/// - Only the reaction author can reclaim the reaction
+/// - Verify that the parent tweet was deleted, either it no longer exists or
+///   it was recreated under the same topic, which gives it a new generation
+/// - Close the tweet reaction account and return rent to reaction author
+///
+/// Counters are not touched, they belonged to the deleted tweet.
+///
+///-------------------------------------------------------------------------------
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn reclaim_reaction(ctx: Context<ReclaimReactionContext>) -> Result<()> {
+    let tweet = &ctx.accounts.tweet;
+
+    if tweet.owner == &crate::ID && !tweet.data_is_empty() {
+        let tweet = Tweet::try_deserialize(&mut &tweet.data.borrow()[..])?;
+
+        if ctx.accounts.tweet_reaction.belongs_to(&tweet) {
+            return Err(TwitterError::ParentTweetExists.into());
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReclaimReactionContext<'info> {
+    #[account(mut)]
+    pub reaction_author: Signer<'info>,
+
+    #[account(
+        mut,
+        close = reaction_author,
+        has_one = reaction_author @ TwitterError::InvalidReactionAuthor,
+        seeds = [
+            TWEET_REACTION_SEED.as_bytes(),
+            reaction_author.key().as_ref(),
+            tweet.key().as_ref(),
+        ],
+        bump = tweet_reaction.bump
+    )]
+    pub tweet_reaction: Account<'info, Reaction>,
+
+    /// CHECK: The reaction's parent tweet, which may already be closed
+    #[account(address = tweet_reaction.parent_tweet)]
+    pub tweet: UncheckedAccount<'info>,
+}
@@ -0,0 +1,53 @@
+//-------------------------------------------------------------------------------
+///
+/// Reclaim revision functionality for the Twitter program
+///
+/// Requirements:
+/// - Only the tweet author can reclaim a revision of their tweet
+/// - Verify that the revision's tweet was deleted, either it no longer exists or
+///   it was recreated under the same topic, which gives it a new generation
+/// - Close the tweet revision account and return rent to the tweet author
+///
+///-------------------------------------------------------------------------------
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn reclaim_revision(ctx: Context<ReclaimRevisionContext>) -> Result<()> {
+    let tweet = &ctx.accounts.tweet;
+
+    if tweet.owner == &crate::ID && !tweet.data_is_empty() {
+        let tweet = Tweet::try_deserialize(&mut &tweet.data.borrow()[..])?;
+
+        if ctx.accounts.tweet_revision.belongs_to(&tweet) {
+            return Err(TwitterError::ParentTweetExists.into());
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReclaimRevisionContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+
+    #[account(
+        mut,
+        close = tweet_author,
+        has_one = tweet_author @ TwitterError::InvalidTweetAuthor,
+        seeds = [
+            TWEET_REVISION_SEED.as_bytes(),
+            tweet.key().as_ref(),
+            tweet_revision.tweet_generation.to_le_bytes().as_ref(),
+            tweet_revision.revision.to_le_bytes().as_ref()
+        ],
+        bump = tweet_revision.bump
+    )]
+    pub tweet_revision: Account<'info, TweetRevision>,
+
+    /// CHECK: The revision's parent tweet, which may already be closed
+    #[account(address = tweet_revision.parent_tweet @ TwitterError::InvalidRevision)]
+    pub tweet: UncheckedAccount<'info>,
+}
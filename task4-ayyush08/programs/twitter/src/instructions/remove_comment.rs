@@ -4,16 +4,39 @@
 /// 
 /// Requirements:
 /// - Close the comment account and return rent to comment author
+/// - For a reply, decrement the reply counter of the parent comment if the
+///   parent still exists
 /// 
-/// NOTE: No implementation logic is needed in the function body - this 
-/// functionality is achieved entirely through account constraints!
+/// NOTE: Closing is achieved entirely through account constraints, the function
+/// body only keeps the parent's reply counter in sync.
 /// 
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
+use crate::errors::TwitterError;
 use crate::states::*;
 
-pub fn remove_comment(_ctx: Context<RemoveCommentContext>) -> Result<()> {
+pub fn remove_comment(ctx: Context<RemoveCommentContext>) -> Result<()> {
+    let Some(parent_key) = ctx.accounts.comment.parent_comment else {
+        return Ok(());
+    };
+
+    let Some(parent_comment) = &ctx.accounts.parent_comment else {
+        return Err(TwitterError::InvalidParentComment.into());
+    };
+
+    if parent_comment.key() != parent_key {
+        return Err(TwitterError::InvalidParentComment.into());
+    }
+
+    // the parent may have been removed before its replies
+    if parent_comment.owner == &crate::ID && !parent_comment.data_is_empty() {
+        let mut parent = Comment::try_deserialize(&mut &parent_comment.data.borrow()[..])?;
+
+        parent.reply_count = parent.reply_count.saturating_sub(1);
+        parent.try_serialize(&mut &mut parent_comment.data.borrow_mut()[..])?;
+    }
+
     Ok(())
 }
 
@@ -28,4 +51,9 @@ pub struct RemoveCommentContext<'info> {
     #[account(mut, close = comment_author, has_one = comment_author)]
 
     pub comment: Account<'info, Comment>,
+
+    /// CHECK: The comment this one replies to, required for replies only.
+    /// It may already be closed, so it is only deserialized while it exists
+    #[account(mut)]
+    pub parent_comment: Option<UncheckedAccount<'info>>,
 }
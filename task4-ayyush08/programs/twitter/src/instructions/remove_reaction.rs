@@ -4,7 +4,8 @@
 ///
 /// Requirements:
 /// - Verify that the tweet reaction exists and belongs to the reaction author
-/// - Decrement the appropriate counter (likes or dislikes) on the tweet
+/// - Verify that the reaction was not made on a deleted tweet with the same address
+/// - Decrement the tweet's counter for the reaction type
 /// - Close the tweet reaction account and return rent to reaction author
 ///
 ///-------------------------------------------------------------------------------
@@ -26,21 +27,12 @@ pub fn remove_reaction(ctx: Context<RemoveReactionContext>) -> Result<()> {
         return Err(TwitterError::InvalidReactionAuthor.into());
     }
 
-    match tweet_reaction.reaction {
-        ReactionType::Like => {
-            if tweet.likes == 0 {
-                return Err(TwitterError::MinLikesReached.into());
-            }
-            tweet.likes -= 1;
-        }
-        ReactionType::Dislike => {
-            if tweet.dislikes == 0 {
-                return Err(TwitterError::MinDislikesReached.into());
-            }
-            tweet.dislikes -= 1;
-        }
+    if !tweet_reaction.belongs_to(tweet) {
+        return Err(TwitterError::ParentTweetDeleted.into());
     }
 
+    tweet.decrement_reaction(tweet_reaction.reaction)?;
+
     Ok(())
 }
 
@@ -0,0 +1,42 @@
+//-------------------------------------------------------------------------------
+///
+/// Remove tweet functionality for the Twitter program
+///
+/// Requirements:
+/// - Only the tweet author can remove the tweet
+/// - Close the tweet account and return rent to the tweet author
+///
+/// NOTE: Closing is achieved entirely through account constraints. Revisions,
+/// reactions and comments are closed by their own authors: revisions through
+/// revision_reclaim, reactions through reaction_reclaim and comments through
+/// comment_remove. Revisions are seeded by the tweet's generation, so a tweet
+/// recreated under the same topic starts a fresh history.
+///
+///-------------------------------------------------------------------------------
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn remove_tweet(_ctx: Context<RemoveTweetContext>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveTweetContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+
+    #[account(
+        mut,
+        close = tweet_author,
+        has_one = tweet_author @ TwitterError::InvalidTweetAuthor,
+        seeds = [
+            tweet.topic.as_bytes(),
+            TWEET_SEED.as_bytes(),
+            tweet_author.key().as_ref()
+        ],
+        bump = tweet.bump
+    )]
+    pub tweet: Account<'info, Tweet>,
+}
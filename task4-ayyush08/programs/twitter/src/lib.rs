@@ -1,4 +1,4 @@
-#![allow(unexpected_cfgs)]
+#![allow(unexpected_cfgs, deprecated, clippy::empty_line_after_doc_comments)]
 
 //===============================================================================
 ///
@@ -6,9 +6,12 @@
 /// 
 /// This is a decentralized Twitter-like program built on Solana that allows users to:
 /// - Create tweets with topics and content
-/// - Add reactions (likes/dislikes) to tweets
-/// - Comment on tweets
+/// - Add reactions (likes, dislikes and emoji-style reactions) to tweets
+/// - Comment on tweets and reply to comments
 /// - Remove their own reactions and comments
+/// - Switch their reaction to another type
+/// - Edit their own tweets, keeping every prior revision
+/// - Delete their own tweets and reclaim reactions and revisions left on deleted tweets
 /// 
 /// INSTRUCTIONS:
 /// Complete the implementation of all instructions by filling in the TODOs.
@@ -25,7 +28,10 @@
 /// SEEDS:
 /// - Tweet: [topic.as_bytes(), TWEET_SEED.as_bytes(), tweet_authority.key().as_ref()]
 /// - TweetReaction: [TWEET_REACTION_SEED.as_bytes(), reaction_author.key().as_ref(), tweet.key().as_ref()]
-/// - Comment: [COMMENT_SEED.as_bytes(), comment_author.key().as_ref(), {hash(comment.content.as_bytes()).to_bytes().as_ref()}, comment.parent_tweet.key().as_ref()]
+/// - Comment: [COMMENT_SEED.as_bytes(), comment_author.key().as_ref(), {hash(comment.content.as_bytes()).to_bytes().as_ref()}, comment.parent_tweet.key().as_ref(), tweet.generation.to_le_bytes().as_ref()]
+/// - Reply: [COMMENT_SEED.as_bytes(), comment_author.key().as_ref(), {hash(comment.content.as_bytes()).to_bytes().as_ref()}, comment.parent_comment.key().as_ref()]
+/// - TweetCounter: [TWEET_COUNTER_SEED.as_bytes(), tweet_authority.key().as_ref()]
+/// - TweetRevision: [TWEET_REVISION_SEED.as_bytes(), tweet.key().as_ref(), tweet.generation.to_le_bytes().as_ref(), revision.to_le_bytes().as_ref()]
 /// 
 /// GOOD LUCK!
 /// 
@@ -54,13 +60,34 @@ pub mod twitter {
     pub fn dislike_tweet(ctx: Context<AddReactionContext>) -> Result<()> {
         add_reaction(ctx, states::ReactionType::Dislike)
     }
+    pub fn react_tweet(ctx: Context<AddReactionContext>, reaction: states::ReactionType) -> Result<()> {
+        add_reaction(ctx, reaction)
+    }
     pub fn reaction_remove(ctx: Context<RemoveReactionContext>) -> Result<()> {
         remove_reaction(ctx)
     }
+    pub fn reaction_change(ctx: Context<ChangeReactionContext>, reaction: states::ReactionType) -> Result<()> {
+        change_reaction(ctx, reaction)
+    }
     pub fn comment_tweet(ctx: Context<AddCommentContext>, comment_content: String) -> Result<()> {
         add_comment(ctx, comment_content)
     }
+    pub fn comment_reply(ctx: Context<AddReplyContext>, reply_content: String) -> Result<()> {
+        add_reply(ctx, reply_content)
+    }
     pub fn comment_remove(ctx: Context<RemoveCommentContext>) -> Result<()> {
         remove_comment(ctx)
     }
+    pub fn edit_tweet(ctx: Context<EditTweetContext>, content: String) -> Result<()> {
+        instructions::edit_tweet(ctx, content)
+    }
+    pub fn tweet_remove(ctx: Context<RemoveTweetContext>) -> Result<()> {
+        remove_tweet(ctx)
+    }
+    pub fn reaction_reclaim(ctx: Context<ReclaimReactionContext>) -> Result<()> {
+        reclaim_reaction(ctx)
+    }
+    pub fn revision_reclaim(ctx: Context<ReclaimRevisionContext>) -> Result<()> {
+        reclaim_revision(ctx)
+    }
 }
@@ -1,17 +1,56 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::TwitterError;
+
 pub const TOPIC_LENGTH: usize = 32;
 pub const CONTENT_LENGTH: usize = 500;
 pub const COMMENT_LENGTH: usize = 500;
+/// Deepest reply allowed, comments on a tweet are at depth 0.
+pub const MAX_REPLY_DEPTH: u8 = 5;
 
 pub const TWEET_SEED: &str = "TWEET_SEED";
 pub const TWEET_REACTION_SEED: &str = "TWEET_REACTION_SEED";
 pub const COMMENT_SEED: &str = "COMMENT_SEED";
+pub const TWEET_REVISION_SEED: &str = "TWEET_REVISION_SEED";
+pub const TWEET_COUNTER_SEED: &str = "TWEET_COUNTER_SEED";
+
+/// Number of `ReactionType` variants, one counter each on `Tweet`.
+pub const REACTION_TYPES: usize = 7;
 
-#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum ReactionType {
     Like,
     Dislike,
+    Love,
+    Laugh,
+    Sad,
+    Angry,
+    Celebrate,
+}
+
+const _: () = assert!(ReactionType::Celebrate as usize + 1 == REACTION_TYPES);
+
+impl ReactionType {
+    /// Position of this reaction's counter in `Tweet::reactions`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    fn max_reached(self) -> TwitterError {
+        match self {
+            ReactionType::Like => TwitterError::MaxLikesReached,
+            ReactionType::Dislike => TwitterError::MaxDislikesReached,
+            _ => TwitterError::MaxReactionsReached,
+        }
+    }
+
+    fn min_reached(self) -> TwitterError {
+        match self {
+            ReactionType::Like => TwitterError::MinLikesReached,
+            ReactionType::Dislike => TwitterError::MinDislikesReached,
+            _ => TwitterError::MinReactionsReached,
+        }
+    }
 }
 
 #[account]
@@ -22,9 +61,57 @@ pub struct Tweet {
     pub topic: String,
     #[max_len(CONTENT_LENGTH)]
     pub content: String,
-    pub likes: u64,
-    pub dislikes: u64,
+    /// Reaction counters, indexed by `ReactionType::index`.
+    pub reactions: [u64; REACTION_TYPES],
     pub bump: u8,
+    pub edit_count: u64,
+    pub last_edited: i64,
+    /// Number of tweets the author created before this one. A tweet deleted and
+    /// recreated under the same topic keeps its address, but never its generation.
+    pub generation: u64,
+}
+
+impl Tweet {
+    /// Counts a new reaction of type `reaction`.
+    pub fn increment_reaction(&mut self, reaction: ReactionType) -> Result<()> {
+        let count = &mut self.reactions[reaction.index()];
+
+        *count = count.checked_add(1).ok_or(reaction.max_reached())?;
+
+        Ok(())
+    }
+
+    /// Uncounts a removed reaction of type `reaction`.
+    pub fn decrement_reaction(&mut self, reaction: ReactionType) -> Result<()> {
+        let count = &mut self.reactions[reaction.index()];
+
+        *count = count.checked_sub(1).ok_or(reaction.min_reached())?;
+
+        Ok(())
+    }
+}
+
+/// Content a tweet had before its `revision`-th edit (0 is the original).
+#[account]
+#[derive(InitSpace)]
+pub struct TweetRevision {
+    pub tweet_author: Pubkey,
+    pub parent_tweet: Pubkey,
+    /// `generation` of the tweet the revision was made on.
+    pub tweet_generation: u64,
+    pub revision: u64,
+    #[max_len(CONTENT_LENGTH)]
+    pub content: String,
+    pub replaced_at: i64,
+    pub bump: u8,
+}
+
+impl TweetRevision {
+    /// Revisions of a deleted tweet stay behind for their author to reclaim,
+    /// a tweet recreated under the same topic starts a new generation.
+    pub fn belongs_to(&self, tweet: &Tweet) -> bool {
+        self.tweet_generation == tweet.generation
+    }
 }
 
 #[account]
@@ -34,6 +121,25 @@ pub struct Reaction {
     pub parent_tweet: Pubkey,
     pub reaction: ReactionType,
     pub bump: u8,
+    /// `generation` of the tweet the reaction was made on.
+    pub tweet_generation: u64,
+}
+
+impl Reaction {
+    /// A tweet deleted and recreated under the same topic keeps its address,
+    /// reactions made on an earlier generation belong to the deleted tweet.
+    pub fn belongs_to(&self, tweet: &Tweet) -> bool {
+        self.tweet_generation == tweet.generation
+    }
+}
+
+/// Tweets created by `tweet_author`, kept when their tweets are deleted.
+#[account]
+#[derive(InitSpace)]
+pub struct TweetCounter {
+    pub tweet_author: Pubkey,
+    pub count: u64,
+    pub bump: u8,
 }
 
 #[account]
@@ -44,4 +150,18 @@ pub struct Comment {
     #[max_len(COMMENT_LENGTH)]
     pub content: String,
     pub bump: u8,
+    /// Comment this one replies to, `None` for comments on the tweet itself.
+    pub parent_comment: Option<Pubkey>,
+    pub depth: u8,
+    pub reply_count: u64,
+    /// `generation` of the tweet the comment or its thread was made on.
+    pub tweet_generation: u64,
+}
+
+impl Comment {
+    /// Comments made on a deleted tweet stay behind for their authors to remove,
+    /// they are not part of a tweet recreated under the same topic.
+    pub fn belongs_to(&self, tweet: &Tweet) -> bool {
+        self.tweet_generation == tweet.generation
+    }
 }
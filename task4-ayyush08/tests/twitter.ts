@@ -9,6 +9,11 @@ import crypto from "crypto";
 const TWEET_SEED = "TWEET_SEED";
 const TWEET_REACTION = "TWEET_REACTION_SEED";
 const COMMENT_SEED = "COMMENT_SEED";
+const TWEET_REVISION_SEED = "TWEET_REVISION_SEED";
+const TWEET_COUNTER_SEED = "TWEET_COUNTER_SEED";
+
+// Position of each reaction type's counter in `Tweet.reactions`
+const REACTION_INDEX = { like: 0, dislike: 1, love: 2, laugh: 3, sad: 4, angry: 5, celebrate: 6 };
 
 describe("twitter", () => {
   const provider = anchor.AnchorProvider.env();
@@ -48,6 +53,11 @@ describe("twitter", () => {
   const unicode_topic = "🚀 Crypto";
   const unicode_content = "Testing with emojis 🎉✨🔥";
 
+  const topic_edit = "Edit Me";
+  const content_edit1 = "First draft with a typo: teh";
+  const content_edit2 = "Second draft, typo fixed: the";
+  const content_edit3 = "Final version of this tweet";
+
   describe("Initialize Tweet", async () => {
     it("Should successfully initialize a tweet with valid topic and content", async () => {
       await airdrop(provider.connection, bob.publicKey);
@@ -57,6 +67,7 @@ describe("twitter", () => {
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -73,6 +84,7 @@ describe("twitter", () => {
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -89,6 +101,7 @@ describe("twitter", () => {
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -105,6 +118,7 @@ describe("twitter", () => {
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -121,6 +135,7 @@ describe("twitter", () => {
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -137,6 +152,7 @@ describe("twitter", () => {
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -156,6 +172,7 @@ describe("twitter", () => {
           {
             tweetAuthority: bob.publicKey,
             tweet: tweet_pkey,
+            tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -175,6 +192,7 @@ describe("twitter", () => {
           {
             tweetAuthority: bob.publicKey,
             tweet: tweet_pkey,
+            tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -195,6 +213,7 @@ describe("twitter", () => {
           {
             tweetAuthority: bob.publicKey,
             tweet: tweet_pkey,
+            tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -212,6 +231,7 @@ describe("twitter", () => {
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -228,6 +248,7 @@ describe("twitter", () => {
         {
           tweetAuthority: charlie.publicKey,
           tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(charlie.publicKey, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([charlie]).rpc({ commitment: "confirmed" })
@@ -537,7 +558,7 @@ describe("twitter", () => {
     it("Should fail when attempting to add comment exceeding length limit", async () => {
 
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice1, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice1, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       let should_fail = "This Should Fail"
       try {
@@ -560,7 +581,7 @@ describe("twitter", () => {
 
     it("Should successfully add comment with valid length to tweet", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       await program.methods.commentTweet(comment_alice2).accounts(
         {
@@ -578,7 +599,7 @@ describe("twitter", () => {
     it("Should successfully add comment with exactly 500 characters (boundary test)", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
       const max_comment = "C".repeat(500);
-      const [comment_pkey, comment_bump] = getCommentAddress(max_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(max_comment, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       await program.methods.commentTweet(max_comment).accounts(
         {
@@ -597,7 +618,7 @@ describe("twitter", () => {
     it("Should successfully add empty comment to tweet", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
       const empty_comment = "";
-      const [comment_pkey, comment_bump] = getCommentAddress(empty_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(empty_comment, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       await program.methods.commentTweet(empty_comment).accounts(
         {
@@ -616,7 +637,7 @@ describe("twitter", () => {
     it("Should successfully add comment with unicode characters and emojis", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
       const unicode_comment = "Great tweet! 🎉✨ Love it! 💯";
-      const [comment_pkey, comment_bump] = getCommentAddress(unicode_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(unicode_comment, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       await program.methods.commentTweet(unicode_comment).accounts(
         {
@@ -635,7 +656,7 @@ describe("twitter", () => {
     it("Should allow multiple users to comment on the same tweet", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
       const charlie_comment = "Charlie's comment here";
-      const [comment_pkey, comment_bump] = getCommentAddress(charlie_comment, charlie.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(charlie_comment, charlie.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       await program.methods.commentTweet(charlie_comment).accounts(
         {
@@ -654,7 +675,7 @@ describe("twitter", () => {
     it("Should fail when attempting to comment on non-existent tweet", async () => {
       const [fake_tweet_pkey, fake_tweet_bump] = getTweetAddress("FakeTweet", bob.publicKey, program.programId);
       const test_comment = "This should fail";
-      const [comment_pkey, comment_bump] = getCommentAddress(test_comment, alice.publicKey, fake_tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(test_comment, alice.publicKey, fake_tweet_pkey, new anchor.BN(0), program.programId);
 
       let should_fail = "This should fail";
       try {
@@ -675,7 +696,7 @@ describe("twitter", () => {
 
     it("Should fail when attempting to create duplicate comment with same content", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       let should_fail = "This should fail";
       try {
@@ -698,7 +719,7 @@ describe("twitter", () => {
   describe("Remove Comment", async () => {
     it("Should successfully remove existing comment from tweet", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       await program.methods.commentRemove().accounts(
         {
@@ -721,7 +742,7 @@ describe("twitter", () => {
     it("Should fail when attempting to remove non-existent comment", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
       const fake_comment = "This comment doesn't exist";
-      const [comment_pkey, comment_bump] = getCommentAddress(fake_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(fake_comment, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       let should_fail = "This should fail";
       try {
@@ -741,7 +762,7 @@ describe("twitter", () => {
     it("Should fail when attempting to remove another user's comment", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
       const unicode_comment = "Great tweet! 🎉✨ Love it! 💯";
-      const [comment_pkey, comment_bump] = getCommentAddress(unicode_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(unicode_comment, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       let should_fail = "This should fail";
       try {
@@ -760,7 +781,7 @@ describe("twitter", () => {
 
     it("Should allow recreating comment with same content after deletion", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       // Recreate the same comment that was deleted
       await program.methods.commentTweet(comment_alice2).accounts(
@@ -778,6 +799,644 @@ describe("twitter", () => {
     });
   });
 
+  describe("Edit Tweet", async () => {
+    it("Should successfully edit a tweet and store the previous content as a revision", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+
+      await program.methods.initialize(topic_edit, content_edit1).accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.editCount.toString(), "0", "New tweet should not have any edits");
+      assert.strictEqual(tweetData.lastEdited.toString(), "0", "New tweet should not have a last edited timestamp");
+      const [revision_pkey, revision_bump] = getRevisionAddress(tweet_pkey, tweetData.generation, 0, program.programId);
+
+      await program.methods.editTweet(content_edit2).accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetRevision: revision_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_edit, content_edit2, 0, 0, tweet_bump
+      )
+      tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.editCount.toString(), "1", "Tweet edit count should be 1 after the first edit");
+      assert.isTrue(tweetData.lastEdited.toNumber() > 0, "Tweet last edited timestamp should be set after an edit");
+
+      await checkRevision(
+        program, revision_pkey, tweet_pkey, 0, content_edit1, revision_bump
+      )
+    });
+
+    it("Should keep every prior revision across multiple edits", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const { generation } = await program.account.tweet.fetch(tweet_pkey);
+      const [revision_pkey, revision_bump] = getRevisionAddress(tweet_pkey, generation, 1, program.programId);
+
+      await program.methods.editTweet(content_edit3).accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetRevision: revision_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_edit, content_edit3, 0, 0, tweet_bump
+      )
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.editCount.toString(), "2", "Tweet edit count should be 2 after the second edit");
+
+      const [first_revision_pkey, first_revision_bump] = getRevisionAddress(tweet_pkey, generation, 0, program.programId);
+      await checkRevision(program, first_revision_pkey, tweet_pkey, 0, content_edit1, first_revision_bump)
+      await checkRevision(program, revision_pkey, tweet_pkey, 1, content_edit2, revision_bump)
+    });
+
+    it("Should fail when attempting to edit a tweet with content exceeding 500 bytes", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const { generation } = await program.account.tweet.fetch(tweet_pkey);
+      const [revision_pkey, revision_bump] = getRevisionAddress(tweet_pkey, generation, 2, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.editTweet(content_bob3).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            tweetRevision: revision_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ContentTooLong", "Expected 'ContentTooLong' error for content longer than 500 bytes");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Editing a tweet should have failed with content longer than 500 bytes")
+    });
+
+    it("Should fail when attempting to edit another user's tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const { generation } = await program.account.tweet.fetch(tweet_pkey);
+      const [revision_pkey, revision_bump] = getRevisionAddress(tweet_pkey, generation, 2, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.editTweet("Alice was here").accounts(
+          {
+            tweetAuthor: alice.publicKey, // Alice trying to edit Bob's tweet
+            tweet: tweet_pkey,
+            tweetRevision: revision_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidTweetAuthor", "Expected 'InvalidTweetAuthor' error when editing someone else's tweet");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to edit someone else's tweet (authorization check)")
+      await checkTweet(program, tweet_pkey, bob.publicKey, topic_edit, content_edit3);
+    });
+  });
+
+  describe("Remove Tweet", async () => {
+    let removed_generation: anchor.BN;
+
+    it("Should fail when attempting to reclaim a reaction on an existing tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.likeTweet().accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.reactionReclaim().accounts(
+          {
+            reactionAuthor: alice.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ParentTweetExists", "Expected 'ParentTweetExists' error when the tweet still exists");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to reclaim a reaction while its tweet exists")
+    });
+
+    it("Should fail when attempting to remove another user's tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.tweetRemove().accounts(
+          {
+            tweetAuthor: alice.publicKey, // Alice trying to remove Bob's tweet
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidTweetAuthor", "Expected 'InvalidTweetAuthor' error when removing someone else's tweet");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to remove someone else's tweet (authorization check)")
+    });
+
+    it("Should fail when attempting to reclaim a revision of an existing tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const { generation } = await program.account.tweet.fetch(tweet_pkey);
+      const [revision_pkey, revision_bump] = getRevisionAddress(tweet_pkey, generation, 0, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.revisionReclaim().accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweetRevision: revision_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ParentTweetExists", "Expected 'ParentTweetExists' error when the tweet still exists");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to reclaim a revision while its tweet exists")
+    });
+
+    it("Should successfully remove a tweet and leave its revisions to be reclaimed", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      removed_generation = (await program.account.tweet.fetch(tweet_pkey)).generation;
+
+      await program.methods.tweetRemove().accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      const tweetInfo = await provider.connection.getAccountInfo(tweet_pkey, "confirmed");
+      assert.isNull(tweetInfo, "Tweet account should be closed after it was removed");
+
+      for (const revision of [0, 1]) {
+        const [revision_pkey, revision_bump] = getRevisionAddress(tweet_pkey, removed_generation, revision, program.programId);
+        const revisionInfo = await provider.connection.getAccountInfo(revision_pkey, "confirmed");
+        assert.isNotNull(revisionInfo, `Revision ${revision} should remain after the tweet was removed`);
+      }
+    });
+
+    it("Should allow the tweet author to reclaim revisions of a removed tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+
+      for (const revision of [0, 1]) {
+        const [revision_pkey, revision_bump] = getRevisionAddress(tweet_pkey, removed_generation, revision, program.programId);
+
+        await program.methods.revisionReclaim().accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweetRevision: revision_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+
+        const accountInfo = await provider.connection.getAccountInfo(revision_pkey, "confirmed");
+        assert.isNull(accountInfo, `Revision ${revision} should be closed after it was reclaimed`);
+      }
+    });
+
+    it("Should allow the reaction author to reclaim a reaction on a removed tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reactionReclaim().accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const accountInfo = await provider.connection.getAccountInfo(reaction_pkey, "confirmed");
+      assert.isNull(accountInfo, "Reaction account should be closed after it was reclaimed");
+    });
+
+    it("Should allow reusing the topic of a removed tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+
+      await program.methods.initialize(topic_edit, content_edit1).accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_edit, content_edit1, 0, 0, tweet_bump
+      )
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.editCount.toString(), "0", "Recreated tweet should start a fresh edit history");
+    });
+
+    it("Should not count reactions on a tweet removed and recreated in the same transaction", async () => {
+      const topic = "Recreated Topic";
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.initialize(topic, content_edit1).accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await program.methods.likeTweet().accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const remove_ix = await program.methods.tweetRemove().accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+        }
+      ).instruction();
+      const recreate_ix = await program.methods.initialize(topic, content_edit2).accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).instruction();
+      await provider.sendAndConfirm(new anchor.web3.Transaction().add(remove_ix, recreate_ix), [bob], { commitment: "confirmed" });
+
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic, content_edit2, 0, 0, tweet_bump
+      )
+
+      // The like belongs to the removed tweet, so its author can reclaim it
+      await program.methods.reactionReclaim().accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const accountInfo = await provider.connection.getAccountInfo(reaction_pkey, "confirmed");
+      assert.isNull(accountInfo, "Reaction on the removed tweet should be reclaimable");
+    });
+
+    it("Should keep comments on a removed tweet out of a tweet recreated under the same topic", async () => {
+      const topic = "Recreated Thread";
+      const comment = "First!";
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic, bob.publicKey, program.programId);
+      const tweet_counter = getTweetCounterAddress(bob.publicKey, program.programId)[0];
+
+      await program.methods.initialize(topic, content_edit1).accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetCounter: tweet_counter,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      const [stale_comment_pkey, stale_comment_bump] = getCommentAddress(comment, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
+      await program.methods.commentTweet(comment).accounts(
+        {
+          commentAuthor: alice.publicKey,
+          comment: stale_comment_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await program.methods.tweetRemove().accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+      await program.methods.initialize(topic, content_edit2).accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetCounter: tweet_counter,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      // The same comment on the recreated tweet gets its own account
+      const [comment_pkey, comment_bump] = getCommentAddress(comment, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
+      assert.notStrictEqual(comment_pkey.toString(), stale_comment_pkey.toString(), "Comments on different generations should not share an address");
+
+      await program.methods.commentTweet(comment).accounts(
+        {
+          commentAuthor: alice.publicKey,
+          comment: comment_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+      await checkComment(program, comment_pkey, alice.publicKey, tweet_pkey, comment, comment_bump)
+
+      const reply = "Replying to the old thread";
+      const [reply_pkey, reply_bump] = getReplyAddress(reply, charlie.publicKey, stale_comment_pkey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.commentReply(reply).accounts(
+          {
+            commentAuthor: charlie.publicKey,
+            reply: reply_pkey,
+            parentComment: stale_comment_pkey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ParentTweetDeleted", "Expected 'ParentTweetDeleted' error when replying to a comment on a removed tweet");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to reply to a comment on a removed tweet")
+    });
+  });
+
+  describe("Change Reaction", async () => {
+    it("Should successfully switch a like to a dislike in place", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.likeTweet().accounts(
+        {
+          reactionAuthor: charlie.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await program.methods.reactionChange({ dislike: {} }).accounts(
+        {
+          reactionAuthor: charlie.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_edit, content_edit1, 0, 1, tweet_bump
+      )
+      await checkReaction(
+        program, reaction_pkey, charlie.publicKey, tweet_pkey, reaction_bump
+      )
+      const reactionData = await program.account.reaction.fetch(reaction_pkey);
+      assert.deepEqual(reactionData.reaction, { dislike: {} }, "Reaction type should be 'dislike' after the change");
+    });
+
+    it("Should leave counters unchanged when switching to the current reaction type", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reactionChange({ dislike: {} }).accounts(
+        {
+          reactionAuthor: charlie.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_edit, content_edit1, 0, 1, tweet_bump
+      )
+    });
+
+    it("Should fail when attempting to change another user's reaction", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.reactionChange({ like: {} }).accounts(
+          {
+            reactionAuthor: alice.publicKey, // Alice trying to change Charlie's reaction
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(error.message.includes("constraint") || error.message.includes("seeds"), "Expected constraint or seeds error when trying to change someone else's reaction")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to change someone else's reaction (authorization check)");
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_edit, content_edit1, 0, 1, tweet_bump
+      )
+    });
+  });
+
+  describe("Reaction Types", async () => {
+    it("Should successfully add an emoji-style reaction to a tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reactTweet({ love: {} }).accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await checkReaction(
+        program, reaction_pkey, alice.publicKey, tweet_pkey, reaction_bump
+      )
+      const reactionData = await program.account.reaction.fetch(reaction_pkey);
+      assert.deepEqual(reactionData.reaction, { love: {} }, "Reaction type should be 'love'");
+      await checkReactionCounts(program, tweet_pkey, { dislike: 1, love: 1 });
+    });
+
+    it("Should move the count between reaction types when changing an emoji-style reaction", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reactionChange({ celebrate: {} }).accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await checkReactionCounts(program, tweet_pkey, { dislike: 1, celebrate: 1 });
+    });
+
+    it("Should decrement the matching counter when removing an emoji-style reaction", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edit, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reactionRemove().accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await checkReactionCounts(program, tweet_pkey, { dislike: 1 });
+    });
+  });
+
+  describe("Reply to Comment", async () => {
+    const reply_charlie = "Alice, you could be nicer to Bob";
+
+    it("Should successfully reply to a comment", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
+      const [reply_pkey, reply_bump] = getReplyAddress(reply_charlie, charlie.publicKey, comment_pkey, program.programId);
+
+      await program.methods.commentReply(reply_charlie).accounts(
+        {
+          commentAuthor: charlie.publicKey,
+          reply: reply_pkey,
+          parentComment: comment_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await checkComment(
+        program, reply_pkey, charlie.publicKey, tweet_pkey, reply_charlie, reply_bump
+      )
+      const replyData = await program.account.comment.fetch(reply_pkey);
+      assert.strictEqual(replyData.parentComment.toString(), comment_pkey.toString(), "Reply should point to its parent comment");
+      assert.strictEqual(replyData.depth, 1, "Reply to a comment should be at depth 1");
+
+      const commentData = await program.account.comment.fetch(comment_pkey);
+      assert.isNull(commentData.parentComment, "Comment on a tweet should not have a parent comment");
+      assert.strictEqual(commentData.replyCount.toString(), "1", "Parent comment should count the reply");
+    });
+
+    it("Should fail when attempting to reply beyond the maximum reply depth", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
+      let [parent_pkey, parent_bump] = getReplyAddress(reply_charlie, charlie.publicKey, comment_pkey, program.programId);
+
+      // Replies at depth 2 to 5 are allowed
+      for (let depth = 2; depth <= 5; depth++) {
+        const content = `Reply at depth ${depth}`;
+        const [reply_pkey, reply_bump] = getReplyAddress(content, alice.publicKey, parent_pkey, program.programId);
+
+        await program.methods.commentReply(content).accounts(
+          {
+            commentAuthor: alice.publicKey,
+            reply: reply_pkey,
+            parentComment: parent_pkey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+
+        parent_pkey = reply_pkey;
+      }
+
+      let should_fail = "This Should Fail"
+      try {
+        const content = "Reply at depth 6";
+        const [reply_pkey, reply_bump] = getReplyAddress(content, alice.publicKey, parent_pkey, program.programId);
+
+        await program.methods.commentReply(content).accounts(
+          {
+            commentAuthor: alice.publicKey,
+            reply: reply_pkey,
+            parentComment: parent_pkey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ReplyTooDeep", "Expected 'ReplyTooDeep' error for a reply beyond the maximum depth");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Replying beyond the maximum depth should have failed")
+    });
+
+    it("Should fail when attempting to remove a reply without its parent comment", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
+      const [reply_pkey, reply_bump] = getReplyAddress(reply_charlie, charlie.publicKey, comment_pkey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.commentRemove().accounts(
+          {
+            commentAuthor: charlie.publicKey,
+            comment: reply_pkey,
+            parentComment: null,
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidParentComment", "Expected 'InvalidParentComment' error when the parent comment is missing");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Removing a reply without its parent comment should have failed")
+    });
+
+    it("Should decrement the parent's reply count when a reply is removed", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
+      const [reply_pkey, reply_bump] = getReplyAddress(reply_charlie, charlie.publicKey, comment_pkey, program.programId);
+
+      await program.methods.commentRemove().accounts(
+        {
+          commentAuthor: charlie.publicKey,
+          comment: reply_pkey,
+          parentComment: comment_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      const accountInfo = await provider.connection.getAccountInfo(reply_pkey, "confirmed");
+      assert.isNull(accountInfo, "Reply account should be closed after removal");
+
+      const commentData = await program.account.comment.fetch(comment_pkey);
+      assert.strictEqual(commentData.replyCount.toString(), "0", "Parent comment should no longer count the removed reply");
+    });
+  });
+
   describe("Edge Cases and Error Handling", async () => {
     it("Should allow tweet author to react to their own tweet", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
@@ -801,7 +1460,7 @@ describe("twitter", () => {
     it("Should allow tweet author to comment on their own tweet", async () => {
       const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
       const bob_comment = "Thanks for the likes everyone!";
-      const [comment_pkey, comment_bump] = getCommentAddress(bob_comment, bob.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(bob_comment, bob.publicKey, tweet_pkey, await getTweetGeneration(program, tweet_pkey), program.programId);
 
       await program.methods.commentTweet(bob_comment).accounts(
         {
@@ -839,7 +1498,7 @@ async function airdrop(connection: any, address: any, amount = 1000000000) {
   await connection.confirmTransaction(await connection.requestAirdrop(address, amount), "confirmed");
 }
 
-function getCommentAddress(comment_content: string, author: PublicKey, parent_tweet: PublicKey, programID: PublicKey) {
+function getCommentAddress(comment_content: string, author: PublicKey, parent_tweet: PublicKey, generation: anchor.BN, programID: PublicKey) {
   let hexString = crypto.createHash('sha256').update(comment_content, 'utf-8').digest('hex');
   let content_seed = Uint8Array.from(Buffer.from(hexString, 'hex'));
 
@@ -850,9 +1509,27 @@ function getCommentAddress(comment_content: string, author: PublicKey, parent_tw
       author.toBuffer(),
       content_seed,
       parent_tweet.toBuffer(),
+      generation.toArrayLike(Buffer, "le", 8),
     ], programID);
 }
 
+function getReplyAddress(reply_content: string, author: PublicKey, parent_comment: PublicKey, programID: PublicKey) {
+  let hexString = crypto.createHash('sha256').update(reply_content, 'utf-8').digest('hex');
+  let content_seed = Uint8Array.from(Buffer.from(hexString, 'hex'));
+
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(COMMENT_SEED),
+      author.toBuffer(),
+      content_seed,
+      parent_comment.toBuffer(),
+    ], programID);
+}
+
+async function getTweetGeneration(program: anchor.Program<Twitter>, tweet: PublicKey) {
+  return (await program.account.tweet.fetch(tweet)).generation;
+}
+
 function getTweetAddress(topic: string, author: PublicKey, programID: PublicKey) {
   return PublicKey.findProgramAddressSync(
     [
@@ -871,6 +1548,24 @@ function getReactionAddress(author: PublicKey, tweet: PublicKey, programID: Publ
     ], programID);
 }
 
+function getTweetCounterAddress(author: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TWEET_COUNTER_SEED),
+      author.toBuffer(),
+    ], programID);
+}
+
+function getRevisionAddress(tweet: PublicKey, generation: anchor.BN, revision: number, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TWEET_REVISION_SEED),
+      tweet.toBuffer(),
+      generation.toArrayLike(Buffer, "le", 8),
+      new anchor.BN(revision).toArrayLike(Buffer, "le", 8),
+    ], programID);
+}
+
 class SolanaError {
   static contains(logs, error): boolean {
     const match = logs?.filter(s => s.includes(error));
@@ -901,10 +1596,12 @@ async function checkTweet(
     assert.strictEqual(tweetData.content, content, `Tweet content should be "${content}" but was "${tweetData.content}"`);
   }
   if (likes || likes == 0) {
-    assert.strictEqual(tweetData.likes.toString(), new anchor.BN(likes).toString(), `Tweet likes should be ${likes} but was ${tweetData.likes.toString()}`)
+    const tweetLikes = tweetData.reactions[REACTION_INDEX.like];
+    assert.strictEqual(tweetLikes.toString(), new anchor.BN(likes).toString(), `Tweet likes should be ${likes} but was ${tweetLikes.toString()}`)
   }
   if (dislikes || dislikes == 0) {
-    assert.strictEqual(tweetData.dislikes.toString(), new anchor.BN(dislikes).toString(), `Tweet dislikes should be ${dislikes} but was ${tweetData.dislikes.toString()}`)
+    const tweetDislikes = tweetData.reactions[REACTION_INDEX.dislike];
+    assert.strictEqual(tweetDislikes.toString(), new anchor.BN(dislikes).toString(), `Tweet dislikes should be ${dislikes} but was ${tweetDislikes.toString()}`)
   }
   if (bump) {
     assert.strictEqual(tweetData.bump.toString(), bump.toString(), `Tweet bump should be ${bump} but was ${tweetData.bump}`)
@@ -955,3 +1652,40 @@ async function checkComment(
     assert.strictEqual(commentnData.bump.toString(), bump.toString(), `Comment bump should be ${bump} but was ${commentnData.bump}`)
   }
 }
+
+async function checkRevision(
+  program: anchor.Program<Twitter>,
+  tweet_revision: PublicKey,
+  parent_tweet?: PublicKey,
+  revision?: number,
+  content?: string,
+  bump?: number,
+) {
+  let revisionData = await program.account.tweetRevision.fetch(tweet_revision);
+
+  if (parent_tweet) {
+    assert.strictEqual(revisionData.parentTweet.toString(), parent_tweet.toString(), `Revision parent tweet should be ${parent_tweet.toString()} but was ${revisionData.parentTweet.toString()}`)
+  }
+  if (revision || revision == 0) {
+    assert.strictEqual(revisionData.revision.toString(), new anchor.BN(revision).toString(), `Revision number should be ${revision} but was ${revisionData.revision.toString()}`)
+  }
+  if (content) {
+    assert.strictEqual(revisionData.content, content, `Revision content should be "${content}" but was "${revisionData.content}"`);
+  }
+  if (bump) {
+    assert.strictEqual(revisionData.bump.toString(), bump.toString(), `Revision bump should be ${bump} but was ${revisionData.bump}`)
+  }
+}
+
+async function checkReactionCounts(
+  program: anchor.Program<Twitter>,
+  tweet: PublicKey,
+  expected: { [reaction: string]: number },
+) {
+  let tweetData = await program.account.tweet.fetch(tweet);
+
+  for (const [reaction, index] of Object.entries(REACTION_INDEX)) {
+    const count = expected[reaction] ?? 0;
+    assert.strictEqual(tweetData.reactions[index].toString(), new anchor.BN(count).toString(), `Tweet ${reaction} count should be ${count} but was ${tweetData.reactions[index].toString()}`)
+  }
+}